@@ -1,17 +1,30 @@
 use clap::Args;
 use std::path::PathBuf;
 
-use pulsedcm_commands_ano::{threading_handling};
+use pulsedcm_commands_ano::{
+    threading_handling, AnoConfig,
+    output::PathTemplate,
+    private::PrivateMode,
+    profile::{ActionOverride, Policy, Profile},
+    pseudonym::PseudonymSource,
+};
+
+use pulsedcm_core::{Confirmation, RunSummary};
 
 use crate::commands::{ArgRun};
 
 #[derive(Args, Debug)]
 pub struct AnoArgs {
     /// If omitted, input files will be overwritten in-place.
-    /// Must be a directory if specified.
+    /// Must be a directory if specified, the input tree being mirrored under it
     #[arg(long)]
     out: Option<PathBuf>,
 
+    /// Output path relative to --out, from the de-identified attributes,
+    /// e.g. {PatientID}/{StudyInstanceUID}/{SeriesNumber}/{InstanceNumber}.dcm
+    #[arg(long, value_name = "TEMPLATE", value_parser = parse_template)]
+    name_template: Option<PathTemplate>,
+
     /// Show the changed args for the file
     /// If multiple files it'll stop processing after the 1st to give an output
     #[arg(short, long, default_value= "false")]
@@ -21,29 +34,143 @@ pub struct AnoArgs {
     /// Otherwise it'd stop before PixelData
     #[arg(long)]
     with_pixel_data: bool,
+
+    /// Secret used to derive the new UIDs.
+    /// Using the same secret gives the same UIDs across separate runs,
+    /// if omitted a random one is used for this run only
+    #[arg(long, value_name = "SECRET")]
+    uid_secret: Option<String>,
+
+    /// Policy tier between basic, moderate and strict
+    #[arg(long, default_value = "strict", value_parser = parse_policy)]
+    policy: Policy,
+
+    /// Supplement 142 options to apply on top of the policy, comma-separated.
+    /// (retain-safe-private, retain-uids, retain-device-id, retain-institution-id,
+    /// retain-patient-chars, retain-long-full-dates, retain-long-modified-dates,
+    /// clean-descriptors, clean-structured-content, clean-graphics)
+    #[arg(long, value_name = "OPTIONS", value_parser = parse_profile)]
+    profile: Option<Profile>,
+
+    /// Force every matched tag (kept ones excepted) to replace, zero or remove
+    #[arg(long, value_parser = parse_action)]
+    action: Option<ActionOverride>,
+
+    /// File of regular expressions (one per line) scrubbed from descriptors by the clean action.
+    /// If omitted, built-in patterns for dates, IDs and titled names are used
+    #[arg(long, value_name = "FILE")]
+    clean_patterns: Option<PathBuf>,
+
+    /// Private tags handling between remove, keep-safe and keep.
+    /// If omitted, keep-safe with the retain-safe-private option, remove otherwise
+    #[arg(long, value_name = "MODE", value_parser = parse_private)]
+    private: Option<PrivateMode>,
+
+    /// Pixel redaction rules file (CTP DicomPixelAnonymizer format): regions to blank
+    /// for the files matching each signature. Implies --with-pixel-data
+    #[arg(long, value_name = "FILE")]
+    redact_rules: Option<PathBuf>,
+
+    /// Audit file of every action applied, with a keyed hash of the original values.
+    /// CSV if the file ends with .csv, JSON lines otherwise
+    #[arg(long, value_name = "FILE")]
+    audit: Option<PathBuf>,
+
+    /// CSV file mapping the original PatientIDs and UIDs to their new values.
+    /// Not encrypted: the original identifiers are written in plain text (PHI),
+    /// only protected by the file being readable by its owner
+    #[arg(long, value_name = "FILE")]
+    mapping: Option<PathBuf>,
+
+    /// Replace PatientID and PatientName with the same pseudonym for every file of a patient:
+    /// `hash` for a subject ID keyed with --uid-secret (required), or a CSV lookup table
+    /// (original PatientID, new PatientID, optional new PatientName)
+    #[arg(long, value_name = "hash|FILE", value_parser = parse_pseudonyms)]
+    pseudonyms: Option<PseudonymSource>,
+
+    /// Re-open the written files and report, for each, the PHI left:
+    /// original values of known PHI tags, patient name, ID or birth date in any string element
+    #[arg(long)]
+    verify: bool,
+
+    /// CSV report of the verification, one row per finding. Implies --verify
+    #[arg(long, value_name = "FILE")]
+    verify_report: Option<PathBuf>,
+
+    /// TOML file of site rules overriding or extending the action of each tag,
+    /// with tag masks such as (50xx,xxxx) and templated values such as @hash(PatientID)
+    #[arg(long, value_name = "FILE")]
+    custom_profile: Option<PathBuf>,
+
+    /// Keep every overwritten file next to its new version, as <file>.orig.
+    /// An existing .orig, from a previous pass, is left as is
+    #[arg(long)]
+    backup: bool,
+
+    /// Journal of the run, for --resume. Defaults to the output directory, or to the
+    /// user cache directory when the input files are overwritten
+    #[arg(long, value_name = "FILE")]
+    journal: Option<PathBuf>,
 } 
 
 impl ArgRun for AnoArgs {
-    fn run_multiple(self, path: &str, files: Vec<PathBuf>,  verbose:bool, jobs:usize) {
-        let mut dry_arg = self.dry;
+    fn run_multiple(self, path: &str, files: Vec<PathBuf>,  verbose:bool, jobs:usize, confirmation: Confirmation, resume: bool, summary: &RunSummary) -> pulsedcm_core::Result<()> {
         let default_out_path = PathBuf::from(&path);
-        match threading_handling(
-            files, 
-            self.out.unwrap_or_else(|| {
+        let config = AnoConfig {
+            input_path: default_out_path.clone(),
+            output_path: self.out.unwrap_or_else(|| {
                 if verbose {
                     println!("out argument has issue when parsing"); 
                 }
                 default_out_path
             }),
-            &mut dry_arg, 
-            self.with_pixel_data,
+            dry: self.dry,
+            with_pixel_data: self.with_pixel_data,
             jobs,
             verbose,
-        ){
-            Ok(_) => {},
-            Err(e) => {
-                eprintln!("Error when running ano command: {}", e);
-            }
+            uid_secret: self.uid_secret,
+            policy: self.policy,
+            profile: self.profile.unwrap_or_default(),
+            action: self.action,
+            clean_patterns: self.clean_patterns,
+            private_mode: self.private,
+            redact_rules: self.redact_rules,
+            audit_path: self.audit,
+            mapping_path: self.mapping,
+            pseudonyms: self.pseudonyms,
+            verify: self.verify,
+            verify_report: self.verify_report,
+            custom_profile: self.custom_profile,
+            name_template: self.name_template,
+            backup: self.backup,
+            journal_path: self.journal,
+            resume,
+            confirmation,
         };
+        threading_handling(files, config, summary)
     }
 }
+
+fn parse_profile(s: &str) -> Result<Profile, String> {
+    s.parse::<Profile>()
+}
+
+fn parse_policy(s: &str) -> Result<Policy, String> {
+    s.parse::<Policy>()
+}
+
+fn parse_action(s: &str) -> Result<ActionOverride, String> {
+    s.parse::<ActionOverride>()
+}
+
+fn parse_private(s: &str) -> Result<PrivateMode, String> {
+    s.parse::<PrivateMode>()
+}
+
+fn parse_pseudonyms(s: &str) -> Result<PseudonymSource, String> {
+    s.parse::<PseudonymSource>()
+}
+
+fn parse_template(s: &str) -> Result<PathTemplate, String> {
+    s.parse::<PathTemplate>()
+}
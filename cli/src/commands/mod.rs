@@ -1,12 +1,13 @@
 use std::path::PathBuf;
 
 use clap::Subcommand;
-use pulsedcm_core::{jobs_handling, collect_dicom_files};
+use pulsedcm_core::{jobs_handling, collect_dicom_files, Confirmation, Result, RunSummary};
 
 // ==== Commands ==== 
 mod tags;
 mod view;
 mod ano;
+mod transcode;
 
 #[derive(Subcommand)]
 pub enum Commands {
@@ -14,20 +15,23 @@ pub enum Commands {
     Tags(tags::TagsArgs),
     View(view::ViewArgs),  
     Ano(ano::AnoArgs),
+    /// Convert files to another transfer syntax
+    Transcode(transcode::TranscodeArgs),
 }
 
-pub fn handling(path: &str, cmd: Commands, verbose: bool, jobs: Option<usize>) {
-    let command = CliCommand::new(path, verbose, jobs);
+pub fn handling(path: &str, cmd: Commands, verbose: bool, jobs: Option<usize>, confirmation: Confirmation, resume: bool, summary: Option<PathBuf>) {
+    let command = CliCommand::new(path, verbose, jobs, confirmation, resume, summary);
     match cmd {
         Commands::Tags(args) => command.run(args),
         Commands::View(args) => command.run(args),
         Commands::Ano(args) => command.run(args),
+        Commands::Transcode(args) => command.run(args),
     }
 }
 
 
 pub trait ArgRun {
-    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize);
+    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize, confirmation: Confirmation, resume: bool, summary: &RunSummary) -> Result<()>;
     //fn run_single(&self, path: &str, file: PathBuf, verbose: bool, jobs: usize);
 }
 
@@ -36,10 +40,12 @@ pub struct CliCommand<'a> {
     pub files: Vec<PathBuf>,
     pub verbose: bool, 
     pub jobs: usize,
-
+    pub confirmation: Confirmation,
+    pub resume: bool,
+    pub summary: Option<PathBuf>, // JSON report of the run
 }
 impl<'a> CliCommand<'a> {
-    pub fn new(path: &'a str, verbose: bool, jobs: Option<usize>) -> Self {
+    pub fn new(path: &'a str, verbose: bool, jobs: Option<usize>, confirmation: Confirmation, resume: bool, summary: Option<PathBuf>) -> Self {
         let files = match collect_dicom_files(path){
             Ok(o) => o, 
             Err(e) => panic!("Couldn't determine a file collection with the provided path: {}", e),
@@ -49,10 +55,27 @@ impl<'a> CliCommand<'a> {
             jobs    : jobs_handling(jobs, files.len()),
             files   : files,
             verbose : verbose,
+            confirmation,
+            resume,
+            summary,
         }
     }
     pub fn run(self, arg: impl ArgRun){
-        arg.run_multiple(self.path, self.files,  self.verbose, self.jobs);
+        let summary = RunSummary::new();
+        if let Err(e) = arg.run_multiple(self.path, self.files,  self.verbose, self.jobs, self.confirmation, self.resume, &summary) {
+            summary.abort(&e);
+        }
+        summary.print();
+        if let Some(path) = &self.summary {
+            if let Err(e) = summary.write(path) {
+                eprintln!("Can't write the summary to {} : {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+        // Partial failures are visible to scripts
+        if summary.failed() {
+            std::process::exit(1);
+        }
         //
         //match self.files.len() {
         //    1.. => arg.run_multiple(self.path, self.files,  self.verbose, self.jobs),
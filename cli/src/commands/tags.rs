@@ -2,6 +2,8 @@ use clap::Args;
 use std::path::PathBuf;
 
 use pulsedcm_commands_tags::{run as tag_run, TagFlags};
+use pulsedcm_core::{Confirmation, RunSummary};
+
 use crate::commands::ArgRun;
 
 #[derive(Args, Debug)]
@@ -31,20 +33,18 @@ pub struct TagsArgs {
 }
 
 impl ArgRun for TagsArgs {
-    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize) { 
-        match tag_run(
+    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize, confirmation: Confirmation, resume: bool, summary: &RunSummary) -> pulsedcm_core::Result<()> {
+        tag_run(
             files, 
             self.kind,
             self.with_pixel_data,
             jobs,
             self.json,
             self.csv,
-        ){
-            Ok(_) => {},
-            Err(e) => {
-                eprintln!("Error when running ano command: {}", e);
-            }
-        };
+            confirmation,
+            resume,
+            summary,
+        )
     }
 }
 
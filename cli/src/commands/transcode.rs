@@ -0,0 +1,49 @@
+use clap::Args;
+use std::path::PathBuf;
+
+use pulsedcm_commands_transcode::{run as transcode_run, syntax::TargetSyntax};
+use pulsedcm_core::{Confirmation, Result, RunSummary};
+
+use crate::commands::ArgRun;
+
+#[derive(Args, Debug)]
+pub struct TranscodeArgs {
+    /// Transfer syntax to convert to: explicit, implicit, deflated, rle, jpeg (baseline, lossy),
+    /// jpeg-lossless, j2k-lossless, j2k (lossy) or a transfer syntax UID
+    #[arg(long, value_name = "SYNTAX", value_parser = parse_syntax)]
+    to: TargetSyntax,
+
+    /// Output directory, the input tree being mirrored under it.
+    /// If omitted, input files will be overwritten in-place
+    #[arg(long)]
+    out: Option<PathBuf>,
+
+    /// Quality of the JPEG Baseline, from 1 to 100. Only with --to jpeg
+    #[arg(long, value_name = "QUALITY", value_parser = clap::value_parser!(u8).range(1..=100))]
+    quality: Option<u8>,
+
+    /// Compression ratio of the lossy JPEG 2000, e.g. 20 for 20:1 (10 by default). Only with --to j2k
+    #[arg(long, value_name = "RATIO")]
+    rate: Option<f32>,
+}
+
+impl ArgRun for TranscodeArgs {
+    fn run_multiple(self, path: &str, files: Vec<PathBuf>, _verbose: bool, jobs: usize, confirmation: Confirmation, resume: bool, summary: &RunSummary) -> Result<()> {
+        transcode_run(
+            files,
+            PathBuf::from(path),
+            self.out,
+            self.to,
+            self.quality,
+            self.rate,
+            jobs,
+            confirmation,
+            resume,
+            summary,
+        )
+    }
+}
+
+fn parse_syntax(s: &str) -> std::result::Result<TargetSyntax, String> {
+    s.parse::<TargetSyntax>()
+}
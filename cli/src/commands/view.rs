@@ -1,7 +1,14 @@
 use clap::Args;
 use std::path::PathBuf;
 
-use pulsedcm_commands_view::{run as view_run};
+use pulsedcm_commands_view::{
+    array::{ArrayExport, ArrayFormat, SidecarTags},
+    frames::{FrameOutput, FrameSelection},
+    run as view_run,
+    window::Windowing,
+};
+use pulsedcm_core::{Confirmation, Result, RunSummary};
+
 use crate::commands::ArgRun;
 #[derive(Args, Debug)]
 pub struct ViewArgs {
@@ -21,6 +28,33 @@ pub struct ViewArgs {
     #[arg(long)]
     out: Option<PathBuf>,
 
+    /// Window of the monochrome images: file (its window, else its VOI LUT, else auto),
+    /// voi-lut, auto (min/max), a preset (lung, bone, brain, abdomen) or CENTER,WIDTH
+    #[arg(long, value_name = "WINDOW", default_value = "file", value_parser = parse_window)]
+    window: Windowing,
+
+    /// Frames of the multi-frame objects, numbered from 1: all, N or FIRST-LAST,
+    /// with an optional :STEP, e.g. 1-100:5
+    #[arg(long, value_name = "FRAMES", default_value = "all", value_parser = parse_frames)]
+    frames: FrameSelection,
+
+    /// Output of the frames: png (one per frame), gif, apng (animated) or sheet (a single grid)
+    #[arg(long, value_name = "OUTPUT", default_value = "png", value_parser = parse_frame_output)]
+    frame_output: FrameOutput,
+
+    /// Write the modality values (rescaled, e.g. HU for CT) as npy, npz or raw arrays
+    /// with a sidecar JSON, instead of PNGs
+    #[arg(long, value_name = "FORMAT", value_parser = parse_export, conflicts_with = "temp")]
+    export: Option<ArrayFormat>,
+
+    /// Stack every series into a single array, named after its Series Instance UID
+    #[arg(long, requires = "export")]
+    stack: bool,
+
+    /// Attributes copied to the sidecar JSON: comma-separated keywords or none
+    #[arg(long, value_name = "TAGS", requires = "export", value_parser = parse_sidecar_tags)]
+    sidecar_tags: Option<SidecarTags>,
+
     /// Number of threads to launch to process
     #[arg(long, value_name="NUMBER")]
     jobs: Option<usize>,
@@ -30,9 +64,9 @@ pub struct ViewArgs {
 
 
 impl ArgRun for ViewArgs {
-    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize) {
+    fn run_multiple(self, path: &str, files: Vec<PathBuf>, verbose: bool, jobs: usize, confirmation: Confirmation, resume: bool, summary: &RunSummary) -> Result<()> {
         let default_out_path = PathBuf::from(&path);
-        match view_run(
+        view_run(
             files, 
             self.open.unwrap_or(0), 
             self.temp, 
@@ -40,14 +74,38 @@ impl ArgRun for ViewArgs {
                 println!("out argument has issue when parsing"); 
                 default_out_path
             }),
+            self.window,
+            self.frames,
+            self.frame_output,
+            self.export.map(|format| ArrayExport {
+                format,
+                stack: self.stack,
+                tags: self.sidecar_tags.unwrap_or_default(),
+            }),
             jobs,
-        ){
-            Ok(_) => {},
-            Err(e) => {
-                eprintln!("Error when running ano command: {}", e);
-            }
-        };
+            confirmation,
+            resume,
+            summary,
+        )
     }
 }
 
+fn parse_window(s: &str) -> std::result::Result<Windowing, String> {
+    s.parse::<Windowing>()
+}
+
+fn parse_frames(s: &str) -> std::result::Result<FrameSelection, String> {
+    s.parse::<FrameSelection>()
+}
+
+fn parse_frame_output(s: &str) -> std::result::Result<FrameOutput, String> {
+    s.parse::<FrameOutput>()
+}
+
+fn parse_export(s: &str) -> std::result::Result<ArrayFormat, String> {
+    s.parse::<ArrayFormat>()
+}
 
+fn parse_sidecar_tags(s: &str) -> std::result::Result<SidecarTags, String> {
+    s.parse::<SidecarTags>()
+}
@@ -1,6 +1,8 @@
 mod commands;
 
 use clap::{Parser};
+use std::path::PathBuf;
+use pulsedcm_core::Confirmation;
 
 #[derive(Parser)]
 #[command(name = "pulsedcm")]
@@ -20,10 +22,33 @@ struct Cli {
     /// Show all changed values
     #[arg(short, long)]
     verbose: bool,
+
+    /// Answer yes to every confirmation (overwriting files, creating folders)
+    #[arg(long, global = true, conflicts_with = "no_clobber")]
+    yes: bool,
+
+    /// Never overwrite an existing file, failing instead of asking
+    #[arg(long, global = true)]
+    no_clobber: bool,
+
+    /// Skip the files completed by a previous run, from its journal in the output directory
+    #[arg(long, global = true)]
+    resume: bool,
+
+    /// Write the outcome of the run, with every failure, as JSON
+    #[arg(long, global = true, value_name = "FILE")]
+    summary: Option<PathBuf>,
 }
 
 
 fn main() {
     let cli = Cli::parse();
-    commands::handling(&cli.path, cli.command, cli.verbose, cli.jobs);
+    let confirmation = if cli.yes {
+        Confirmation::Yes
+    } else if cli.no_clobber {
+        Confirmation::NoClobber
+    } else {
+        Confirmation::Ask
+    };
+    commands::handling(&cli.path, cli.command, cli.verbose, cli.jobs, confirmation, cli.resume, cli.summary);
 }
@@ -0,0 +1,232 @@
+use std::cell::{Ref, RefCell};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions, Permissions};
+use std::io::{BufWriter, Write};
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::Path;
+use std::sync::Mutex;
+
+use csv::{Reader, Writer, WriterBuilder};
+use serde::Serialize;
+use pulsedcm_core::{InMemElement, PulseError, PulseErrorKind, Result, StandardDataDictionary, DataDictionary, Tag};
+
+use crate::uid::UidGenerator;
+
+/// One action applied to one element of a file
+#[derive(Debug)]
+pub struct AuditRecord {
+    pub tag: Tag,
+    pub action: &'static str,
+    pub original_hash: String,
+}
+
+/// What was changed in one file, filled while de-identifying it
+#[derive(Debug, Default)]
+pub struct FileTrail {
+    records: RefCell<Vec<AuditRecord>>,
+    mappings: RefCell<Vec<(&'static str, String, String)>>,
+}
+
+impl FileTrail {
+    /// Record the action applied to an element, with the keyed hash of its original value
+    pub fn record(&self, uids: &UidGenerator, elem: Option<&InMemElement>, tag: Tag, action: &'static str) {
+        let original_hash = elem
+            .and_then(|e| e.value().primitive())
+            .map(|v| hex(&uids.digest_bytes(&v.to_bytes())))
+            .unwrap_or_default();
+        self.records.borrow_mut().push(AuditRecord { tag, action, original_hash });
+    }
+
+    /// Record an original value and the value replacing it, for the mapping file
+    pub fn map(&self, kind: &'static str, original: &str, new: &str) {
+        self.mappings
+            .borrow_mut()
+            .push((kind, original.to_string(), new.to_string()));
+    }
+
+    pub fn records(&self) -> Ref<'_, [AuditRecord]> {
+        Ref::map(self.records.borrow(), |r| r.as_slice())
+    }
+}
+
+#[derive(Serialize)]
+struct AuditEntry<'a> {
+    input: &'a str,
+    output: &'a str,
+    tag: String,
+    name: &'a str,
+    action: &'a str,
+    original_hash: &'a str,
+}
+
+enum AuditWriter {
+    Csv(Box<Writer<File>>),
+    JsonLines(BufWriter<File>),
+}
+
+/// Run-wide audit file, CSV if the file ends with `.csv`, JSON lines otherwise
+pub struct AuditLog {
+    writer: Mutex<AuditWriter>,
+}
+
+impl AuditLog {
+    /// On resume the rows of the interrupted run are kept and the new ones appended
+    pub fn create(path: &Path, resume: bool) -> Result<Self> {
+        let file = open_private(path, resume)?;
+        let is_csv = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("csv"));
+        let writer = if is_csv {
+            // The header is already there when appending to a previous audit
+            let has_headers = file.metadata()?.len() == 0;
+            AuditWriter::Csv(Box::new(WriterBuilder::new().has_headers(has_headers).from_writer(file)))
+        } else {
+            AuditWriter::JsonLines(BufWriter::new(file))
+        };
+        Ok(Self { writer: Mutex::new(writer) })
+    }
+
+    pub fn write_file(&self, input: &Path, output: &Path, records: &[AuditRecord]) -> Result<()> {
+        let input = input.to_string_lossy();
+        let output = output.to_string_lossy();
+        let mut writer = self.writer.lock()?;
+        for record in records {
+            let name = StandardDataDictionary
+                .by_tag(record.tag)
+                .map(|entry| entry.alias)
+                .unwrap_or("Unknown");
+            let entry = AuditEntry {
+                input: &input,
+                output: &output,
+                tag: format!("({:04X},{:04X})", record.tag.group(), record.tag.element()),
+                name,
+                action: record.action,
+                original_hash: &record.original_hash,
+            };
+            match &mut *writer {
+                AuditWriter::Csv(w) => w.serialize(&entry)?,
+                AuditWriter::JsonLines(w) => {
+                    serde_json::to_writer(&mut *w, &entry).map_err(std::io::Error::from)?;
+                    w.write_all(b"\n")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flush(&self) -> Result<()> {
+        match &mut *self.writer.lock()? {
+            AuditWriter::Csv(w) => w.flush()?,
+            AuditWriter::JsonLines(w) => w.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Original values and their replacement (PatientID, UIDs), for re-identification by an honest broker.
+/// Written in plain text, the file is PHI
+#[derive(Debug, Default)]
+pub struct Mapping {
+    entries: Mutex<BTreeMap<(&'static str, String), String>>,
+}
+
+impl Mapping {
+    /// On resume the entries of the interrupted run are loaded back, the files already done
+    /// being skipped and not adding theirs again
+    pub fn open(path: &Path, resume: bool) -> Result<Self> {
+        let mapping = Self::default();
+        if !resume || !path.exists() {
+            return Ok(mapping);
+        }
+        let mut entries = mapping.entries.lock()?;
+        for row in Reader::from_path(path)?.records() {
+            let row = row?;
+            let kind = match &row[0] {
+                "PatientID" => "PatientID",
+                "UID" => "UID",
+                other => {
+                    return Err(PulseError::new(
+                        PulseErrorKind::IO(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "Invalid mapping file",
+                        )),
+                        format!("Unknown kind {} in {}", other, path.display()),
+                    ))
+                }
+            };
+            entries.insert((kind, row[1].to_string()), row[2].to_string());
+        }
+        drop(entries);
+        Ok(mapping)
+    }
+
+    pub fn extend(&self, trail: &FileTrail) -> Result<()> {
+        let mut entries = self.entries.lock()?;
+        for (kind, original, new) in trail.mappings.borrow().iter() {
+            entries.insert((kind, original.clone()), new.clone());
+        }
+        Ok(())
+    }
+
+    /// Written readable by its owner only, the file holds the original identifiers
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = open_private(path, false)?;
+        let mut wrt = Writer::from_writer(file);
+        wrt.write_record(["kind", "original", "pseudonym"])?;
+        for ((kind, original), new) in self.entries.lock()?.iter() {
+            wrt.write_record([*kind, original.as_str(), new.as_str()])?;
+        }
+        wrt.flush()?;
+        Ok(())
+    }
+}
+
+/// Truncated, appended to or created readable by its owner only. The mode given to `open`
+/// only applies to new files, an existing file gets its permissions set afterwards.
+fn open_private(path: &Path, append: bool) -> Result<File> {
+    let file = OpenOptions::new()
+        .write(!append)
+        .append(append)
+        .create(true)
+        .truncate(!append)
+        .mode(0o600)
+        .open(path)?;
+    file.set_permissions(Permissions::from_mode(0o600))?;
+    Ok(file)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{metadata, read_to_string, remove_dir_all, set_permissions};
+
+    #[test]
+    fn private_on_every_open() {
+        let dir = std::env::temp_dir().join(format!("pulsedcm-audit-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("mapping.csv");
+
+        std::fs::write(&path, "previous run").unwrap();
+        set_permissions(&path, Permissions::from_mode(0o644)).unwrap();
+        let trail = FileTrail::default();
+        trail.map("PatientID", "AB12", "SUBJ-1");
+        let mapping = Mapping::default();
+        mapping.extend(&trail).unwrap();
+        mapping.write(&path).unwrap();
+
+        assert_eq!(metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        assert_eq!(read_to_string(&path).unwrap(), "kind,original,pseudonym\nPatientID,AB12,SUBJ-1\n");
+
+        let audit = dir.join("audit.csv");
+        std::fs::write(&audit, "").unwrap();
+        set_permissions(&audit, Permissions::from_mode(0o644)).unwrap();
+        AuditLog::create(&audit, false).unwrap();
+        assert_eq!(metadata(&audit).unwrap().permissions().mode() & 0o777, 0o600);
+        remove_dir_all(&dir).unwrap();
+    }
+}
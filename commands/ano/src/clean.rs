@@ -0,0 +1,277 @@
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+
+use chrono::{NaiveDate, TimeDelta};
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, InMemDicomObject, PrimitiveValue, PulseError, PulseErrorKind,
+    Result, Tag, VR,
+};
+use regex::{Captures, Regex, RegexBuilder};
+
+use crate::uid::UidGenerator;
+
+/// Dates are shifted back by 1 to this number of days
+const MAX_DATE_SHIFT_DAYS: u64 = 3650;
+
+/// Patterns scrubbed from free text descriptors when no pattern file is given
+pub const DEFAULT_CLEAN_PATTERNS: [&str; 4] = [
+    // Dates: 2023-01-31, 2023/01/31, 20230131
+    r"\b(19|20)\d{2}[-/.]?(0[1-9]|1[0-2])[-/.]?(0[1-9]|[12]\d|3[01])\b",
+    // Dates: 31/01/2023, 1-31-23
+    r"\b\d{1,2}[-/.]\d{1,2}[-/.]\d{2,4}\b",
+    // Identifiers: digit runs, optionally prefixed by a few letters (MRN, accession...)
+    r"\b[A-Za-z]{0,4}\d{5,}\b",
+    // Titled person names: Dr. Name, Mrs Name...
+    r"\b(Dr|Mr|Mrs|Ms|Miss|Prof)\.?\s+[A-Z][A-Za-z'-]+",
+];
+
+/// Patient attributes whose values are scrubbed from the free text of the same file
+const IDENTIFYING_TAGS: [Tag; 6] = [
+    tags::PATIENT_NAME,
+    tags::PATIENT_ID,
+    Tag(0x0010, 0x1000), // Other Patient IDs (retired)
+    tags::OTHER_PATIENT_NAMES,
+    tags::PATIENT_BIRTH_NAME,
+    tags::ACCESSION_NUMBER,
+];
+
+/// Run-wide configuration of the `C` action
+#[derive(Debug)]
+pub struct Cleaner {
+    patterns: Vec<Regex>,
+}
+
+impl Default for Cleaner {
+    fn default() -> Self {
+        Self::new(&DEFAULT_CLEAN_PATTERNS).expect("Default clean patterns should compile")
+    }
+}
+
+impl Cleaner {
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|p| {
+                Regex::new(p.as_ref()).map_err(|e| {
+                    PulseError::new(
+                        PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidData, e)),
+                        format!("Invalid clean pattern '{}'", p.as_ref()),
+                    )
+                })
+            })
+            .collect::<Result<Vec<Regex>>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// One regular expression per line, empty lines and lines starting with `#` are skipped
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = read_to_string(path)?;
+        let patterns: Vec<&str> = content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .collect();
+        Self::new(&patterns)
+    }
+}
+
+/// Cleaning state of one file, derived from its patient
+pub struct PatientCleaner<'a> {
+    cleaner: &'a Cleaner,
+    date_offset: i64,
+    identifiers: Option<Regex>,
+}
+
+impl<'a> PatientCleaner<'a> {
+    /// Must be built before any modification of the dataset
+    pub fn new(cleaner: &'a Cleaner, uids: &UidGenerator, data: &InMemDicomObject) -> Self {
+        let value = |tag| {
+            data.get(tag)
+                .and_then(|e| e.to_str().ok())
+                .map(|s| s.trim_end_matches(['\0', ' ']).trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+        // Keyed on the patient so every file of a patient gets the same shift,
+        // which keeps the intervals between exams. Without a PatientID, on the study
+        // so unrelated patients don't share a shift
+        let key = match (value(tags::PATIENT_ID), value(tags::STUDY_INSTANCE_UID)) {
+            (Some(patient_id), _) => format!("DateOffset:{}", patient_id),
+            (None, Some(study)) => format!("DateOffset:Study:{}", study),
+            (None, None) => format!("DateOffset:Instance:{}", value(tags::SOP_INSTANCE_UID).unwrap_or_default()),
+        };
+        let digest = uids.digest(&key);
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        let days = u64::from_be_bytes(bytes) % MAX_DATE_SHIFT_DAYS + 1;
+
+        Self {
+            cleaner,
+            date_offset: -(days as i64),
+            identifiers: identifiers_regex(data),
+        }
+    }
+
+    /// Number of days added to every cleaned date of this patient
+    pub fn date_offset(&self) -> i64 {
+        self.date_offset
+    }
+
+    /// Cleaned value, `None` when the VR can't be cleaned and must get a dummy instead
+    pub fn clean(&self, value: &PrimitiveValue, vr: &VR) -> Option<PrimitiveValue> {
+        if value.multiplicity() == 0 {
+            return Some(value.clone());
+        }
+        let values = value.to_multi_str();
+        let cleaned: Option<Vec<String>> = match vr {
+            VR::DA => values.iter().map(|v| shift_date(v, self.date_offset)).collect(),
+            VR::DT => values.iter().map(|v| shift_datetime(v, self.date_offset)).collect(),
+            // Shifting by whole days keeps the time of day
+            VR::TM => return Some(value.clone()),
+            VR::LO | VR::SH | VR::ST | VR::LT | VR::UT | VR::UC => {
+                Some(values.iter().map(|v| self.scrub(v)).collect())
+            }
+            _ => None,
+        };
+        cleaned.map(|v| PrimitiveValue::Strs(v.into()))
+    }
+
+    fn scrub(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        let patterns = self.identifiers.iter().chain(self.cleaner.patterns.iter());
+        for pattern in patterns {
+            // Same length mask so the VR maximum length still holds
+            text = pattern
+                .replace_all(&text, |caps: &Captures| "*".repeat(caps[0].chars().count()))
+                .into_owned();
+        }
+        text
+    }
+}
+
+fn identifiers_regex(data: &InMemDicomObject) -> Option<Regex> {
+    let mut names: Vec<String> = Vec::new();
+    let mut ids: Vec<String> = Vec::new();
+    for tag in IDENTIFYING_TAGS {
+        let Some(elem) = data.get(tag) else { continue };
+        let Ok(values) = elem.to_multi_str() else { continue };
+        for value in values.iter() {
+            if elem.vr() == VR::PN {
+                names.extend(
+                    value
+                        .split(|c: char| c == '^' || c == '=' || c.is_whitespace())
+                        .filter(|part| part.chars().count() > 1)
+                        .map(regex::escape),
+                );
+            } else if value.trim().chars().count() > 1 {
+                ids.push(regex::escape(value.trim()));
+            }
+        }
+    }
+
+    let mut alternatives = Vec::new();
+    if !names.is_empty() {
+        alternatives.push(format!(r"\b(?:{})\b", names.join("|")));
+    }
+    if !ids.is_empty() {
+        alternatives.push(format!("(?:{})", ids.join("|")));
+    }
+    if alternatives.is_empty() {
+        return None;
+    }
+    RegexBuilder::new(&alternatives.join("|"))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+fn shift_date(date: &str, days: i64) -> Option<String> {
+    let date = NaiveDate::parse_from_str(date.trim(), "%Y%m%d").ok()?;
+    let shifted = date.checked_add_signed(TimeDelta::days(days))?;
+    Some(shifted.format("%Y%m%d").to_string())
+}
+
+/// Only the date part is shifted, partial dates (YYYY, YYYYMM) can't be and return `None`
+fn shift_datetime(datetime: &str, days: i64) -> Option<String> {
+    let datetime = datetime.trim();
+    if datetime.len() < 8 || !datetime.is_char_boundary(8) {
+        return None;
+    }
+    let (date, rest) = datetime.split_at(8);
+    Some(format!("{}{}", shift_date(date, days)?, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::DataElement;
+
+    fn data(elements: &[(Tag, VR, &str)]) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter(
+            elements.iter().map(|(tag, vr, value)| DataElement::new(*tag, *vr, PrimitiveValue::from(*value))),
+        )
+    }
+
+    #[test]
+    fn shift_dates() {
+        assert_eq!(shift_date("20240301", -1).as_deref(), Some("20240229"));
+        assert_eq!(shift_date(" 20230101 ", -365).as_deref(), Some("20220101"));
+        assert_eq!(shift_date("2023", -1), None);
+        assert_eq!(shift_date("20231341", -1), None);
+    }
+
+    #[test]
+    fn shift_datetimes() {
+        assert_eq!(shift_datetime("20240301123045.5+0100", -1).as_deref(), Some("20240229123045.5+0100"));
+        assert_eq!(shift_datetime("20240301", -1).as_deref(), Some("20240229"));
+        // Partial dates can't be shifted
+        assert_eq!(shift_datetime("202403", -1), None);
+    }
+
+    #[test]
+    fn scrub_with_same_length_masks() {
+        let cleaner = Cleaner::default();
+        let uids = UidGenerator::new("secret");
+        let data = data(&[(tags::PATIENT_NAME, VR::PN, "Doe^John"), (tags::PATIENT_ID, VR::LO, "AB12")]);
+        let patient = PatientCleaner::new(&cleaner, &uids, &data);
+
+        let text = "John DOE AB12 seen 2023-01-31 by Dr. Smith, MRN123456";
+        let scrubbed = patient.scrub(text);
+        assert_eq!(scrubbed, "**** *** **** seen ********** by *********, *********");
+        assert_eq!(scrubbed.chars().count(), text.chars().count());
+    }
+
+    #[test]
+    fn clean_by_vr() {
+        let cleaner = Cleaner::default();
+        let uids = UidGenerator::new("secret");
+        let patient = PatientCleaner::new(&cleaner, &uids, &data(&[(tags::PATIENT_ID, VR::LO, "AB12")]));
+        let days = patient.date_offset();
+        assert!((-(MAX_DATE_SHIFT_DAYS as i64)..0).contains(&days));
+
+        let shifted = patient.clean(&PrimitiveValue::from("20240301"), &VR::DA).unwrap();
+        assert_eq!(shifted.to_str(), shift_date("20240301", days).unwrap());
+        let time = PrimitiveValue::from("123045");
+        assert_eq!(patient.clean(&time, &VR::TM), Some(time));
+        // Nothing to clean in a person name, it gets a dummy
+        assert_eq!(patient.clean(&PrimitiveValue::from("Doe^John"), &VR::PN), None);
+    }
+
+    #[test]
+    fn date_offset_keys() {
+        let cleaner = Cleaner::default();
+        let uids = UidGenerator::new("secret");
+        let offset = |elements: &[(Tag, VR, &str)]| PatientCleaner::new(&cleaner, &uids, &data(elements)).date_offset();
+
+        // Same patient, same shift whatever the study
+        assert_eq!(
+            offset(&[(tags::PATIENT_ID, VR::LO, "AB12"), (tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3")]),
+            offset(&[(tags::PATIENT_ID, VR::LO, "AB12"), (tags::STUDY_INSTANCE_UID, VR::UI, "1.2.4")]),
+        );
+        // Without PatientID, studies don't share the shift of an empty PatientID
+        let anonymous = [(tags::PATIENT_ID, VR::LO, ""), (tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3")];
+        assert_eq!(offset(&anonymous), offset(&[(tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3")]));
+        assert_ne!(offset(&anonymous), offset(&[(tags::STUDY_INSTANCE_UID, VR::UI, "1.2.4")]));
+        assert_ne!(offset(&anonymous), offset(&[(tags::PATIENT_ID, VR::LO, "")]));
+    }
+}
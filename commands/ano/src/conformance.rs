@@ -0,0 +1,306 @@
+use std::cell::Cell;
+
+use phf::*;
+use pulsedcm_core::{dicom_dictionary_std::tags, InMemDicomObject, Tag};
+
+/// Requirement type of an attribute in the IOD of the file (PS3.3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    Type1, // Required, non-zero length
+    Type2, // Required, may be zero length
+    Type3, // Optional
+}
+
+// Type of the compound action attributes in the module they usually belong to,
+// conditional types (1C/2C) are evaluated per file in `Requirements::of`.
+// Attributes not listed keep a value, as a Type 2 attribute would.
+static DEFAULT_TYPES: phf::Map<(u16, u16), Requirement> = phf_map! {
+    (0x0008u16, 0x0012u16) => Requirement::Type3, // Instance Creation Date, SOP Common
+    (0x0008u16, 0x0013u16) => Requirement::Type3, // Instance Creation Time, SOP Common
+    (0x0008u16, 0x0021u16) => Requirement::Type3, // Series Date, General Series
+    (0x0008u16, 0x0022u16) => Requirement::Type3, // Acquisition Date, General Image
+    (0x0008u16, 0x0023u16) => Requirement::Type2, // Content Date, General Image (2C)
+    (0x0008u16, 0x002Au16) => Requirement::Type3, // Acquisition DateTime, General Image
+    (0x0008u16, 0x0031u16) => Requirement::Type3, // Series Time, General Series
+    (0x0008u16, 0x0032u16) => Requirement::Type3, // Acquisition Time, General Image
+    (0x0008u16, 0x0033u16) => Requirement::Type2, // Content Time, General Image (2C)
+    (0x0008u16, 0x0080u16) => Requirement::Type3, // Institution Name, General Equipment
+    (0x0008u16, 0x0082u16) => Requirement::Type3, // Institution Code Sequence, General Equipment
+    (0x0008u16, 0x1010u16) => Requirement::Type3, // Station Name, General Equipment
+    (0x0008u16, 0x1070u16) => Requirement::Type3, // Operators' Name, General Series
+    (0x0008u16, 0x1072u16) => Requirement::Type3, // Operator Identification Sequence, General Series
+    (0x0008u16, 0x1110u16) => Requirement::Type3, // Referenced Study Sequence, General Study
+    (0x0008u16, 0x1111u16) => Requirement::Type3, // Referenced Performed Procedure Step Sequence, General Series
+    (0x0008u16, 0x1140u16) => Requirement::Type3, // Referenced Image Sequence, General Image
+    (0x0008u16, 0x2112u16) => Requirement::Type3, // Source Image Sequence, General Image
+    (0x0010u16, 0x0020u16) => Requirement::Type2, // Patient ID, Patient
+    (0x0010u16, 0x2203u16) => Requirement::Type3, // Patient's Sex Neutered, Patient (2C)
+    (0x0018u16, 0x0010u16) => Requirement::Type2, // Contrast/Bolus Agent, Contrast/Bolus
+    (0x0018u16, 0x1000u16) => Requirement::Type3, // Device Serial Number, General Equipment
+    (0x0018u16, 0x1030u16) => Requirement::Type3, // Protocol Name, General Series
+    (0x0018u16, 0x1400u16) => Requirement::Type3, // Acquisition Device Processing Description, DX Image
+    (0x0018u16, 0x700Au16) => Requirement::Type3, // Detector ID, DX Detector
+    (0x0018u16, 0x700Cu16) => Requirement::Type3, // Date of Last Detector Calibration, DX Detector
+    (0x0018u16, 0x700Eu16) => Requirement::Type3, // Time of Last Detector Calibration, DX Detector
+    (0x0018u16, 0x9516u16) => Requirement::Type3, // Start Acquisition DateTime
+    (0x0018u16, 0x9517u16) => Requirement::Type3, // End Acquisition DateTime
+    (0x0018u16, 0x9919u16) => Requirement::Type2, // Instruction Performed DateTime
+    (0x0032u16, 0x1060u16) => Requirement::Type3, // Requested Procedure Description, Request Attributes
+    (0x0040u16, 0x0555u16) => Requirement::Type3, // Acquisition Context Sequence
+    (0x0040u16, 0xA032u16) => Requirement::Type3, // Observation DateTime
+    (0x0070u16, 0x0084u16) => Requirement::Type2, // Content Creator's Name, Content Identification
+    (0x2200u16, 0x0002u16) => Requirement::Type3, // Label Text, Media Creation Management
+    (0x2200u16, 0x0005u16) => Requirement::Type3, // Barcode Value, Media Creation Management
+    (0x3008u16, 0x0054u16) => Requirement::Type2, // First Treatment Date, RT Treatment Summary Record
+    (0x3008u16, 0x0056u16) => Requirement::Type2, // Most Recent Treatment Date, RT Treatment Summary Record
+    (0x3008u16, 0x0105u16) => Requirement::Type2, // Source Serial Number, RT Brachy Treatment Record
+    (0x3008u16, 0x0250u16) => Requirement::Type2, // Treatment Date, RT General Treatment Record
+    (0x3008u16, 0x0251u16) => Requirement::Type2, // Treatment Time, RT General Treatment Record
+    (0x300Au16, 0x0006u16) => Requirement::Type2, // RT Plan Date, RT General Plan
+    (0x300Au16, 0x0007u16) => Requirement::Type2, // RT Plan Time, RT General Plan
+    (0x300Au16, 0x00B2u16) => Requirement::Type2, // Treatment Machine Name, RT Beams
+    (0x300Eu16, 0x0008u16) => Requirement::Type3, // Reviewer Name, Approval (2C)
+    (0x3010u16, 0x004Cu16) => Requirement::Type3, // Intended Phase Start Date
+    (0x3010u16, 0x004Du16) => Requirement::Type3, // Intended Phase End Date
+    (0x3010u16, 0x0056u16) => Requirement::Type3, // RT Treatment Approach Label
+    (0x3010u16, 0x0077u16) => Requirement::Type3, // Treatment Site
+};
+
+/// Attribute types of one level of a dataset
+type Types = &'static [((u16, u16), Requirement)];
+
+// Multi-frame Functional Groups and Enhanced General Equipment modules
+const ENHANCED: Types = &[
+    ((0x0008, 0x0023), Requirement::Type1), // Content Date
+    ((0x0008, 0x0033), Requirement::Type1), // Content Time
+    ((0x0008, 0x002A), Requirement::Type1), // Acquisition DateTime (1C)
+    ((0x0018, 0x1000), Requirement::Type1), // Device Serial Number
+];
+
+// PET Series and PET Image modules
+const PET: Types = &[
+    ((0x0008, 0x0021), Requirement::Type1), // Series Date
+    ((0x0008, 0x0031), Requirement::Type1), // Series Time
+    ((0x0008, 0x0022), Requirement::Type2), // Acquisition Date
+    ((0x0008, 0x0032), Requirement::Type2), // Acquisition Time
+];
+
+// Acquisition Context module
+const ACQUISITION_CONTEXT: Types = &[
+    ((0x0040, 0x0555), Requirement::Type2), // Acquisition Context Sequence
+];
+
+const ENHANCED_ACQUISITION_CONTEXT: Types = &[
+    ((0x0008, 0x0023), Requirement::Type1), // Content Date
+    ((0x0008, 0x0033), Requirement::Type1), // Content Time
+    ((0x0008, 0x002A), Requirement::Type1), // Acquisition DateTime (1C)
+    ((0x0018, 0x1000), Requirement::Type1), // Device Serial Number
+    ((0x0040, 0x0555), Requirement::Type2), // Acquisition Context Sequence
+];
+
+// SR Document General module
+const SR_DOCUMENT: Types = &[
+    ((0x0008, 0x0023), Requirement::Type1), // Content Date
+    ((0x0008, 0x0033), Requirement::Type1), // Content Time
+];
+
+/// Attribute types differing from `DEFAULT_TYPES`, by SOP Class UID
+static IOD_TYPES: phf::Map<&'static str, Types> = phf_map! {
+    "1.2.840.10008.5.1.4.1.1.2.1" => ENHANCED, // Enhanced CT
+    "1.2.840.10008.5.1.4.1.1.2.2" => ENHANCED, // Legacy Converted Enhanced CT
+    "1.2.840.10008.5.1.4.1.1.4.1" => ENHANCED, // Enhanced MR
+    "1.2.840.10008.5.1.4.1.1.4.3" => ENHANCED, // Enhanced MR Color
+    "1.2.840.10008.5.1.4.1.1.4.4" => ENHANCED, // Legacy Converted Enhanced MR
+    "1.2.840.10008.5.1.4.1.1.12.1.1" => ENHANCED, // Enhanced XA
+    "1.2.840.10008.5.1.4.1.1.12.2.1" => ENHANCED, // Enhanced XRF
+    "1.2.840.10008.5.1.4.1.1.13.1.1" => ENHANCED, // X-Ray 3D Angiographic
+    "1.2.840.10008.5.1.4.1.1.13.1.2" => ENHANCED, // X-Ray 3D Craniofacial
+    "1.2.840.10008.5.1.4.1.1.13.1.3" => ENHANCED, // Breast Tomosynthesis
+    "1.2.840.10008.5.1.4.1.1.66.4" => ENHANCED, // Segmentation
+    "1.2.840.10008.5.1.4.1.1.128.1" => ENHANCED, // Legacy Converted Enhanced PET
+    "1.2.840.10008.5.1.4.1.1.130" => ENHANCED, // Enhanced PET
+    "1.2.840.10008.5.1.4.1.1.6.2" => ENHANCED_ACQUISITION_CONTEXT, // Enhanced US Volume
+    "1.2.840.10008.5.1.4.1.1.77.1.6" => ENHANCED_ACQUISITION_CONTEXT, // VL Whole Slide Microscopy
+    "1.2.840.10008.5.1.4.1.1.128" => PET, // PET
+    "1.2.840.10008.5.1.4.1.1.1.1" => ACQUISITION_CONTEXT, // Digital X-Ray For Presentation
+    "1.2.840.10008.5.1.4.1.1.1.1.1" => ACQUISITION_CONTEXT, // Digital X-Ray For Processing
+    "1.2.840.10008.5.1.4.1.1.1.2" => ACQUISITION_CONTEXT, // Digital Mammography For Presentation
+    "1.2.840.10008.5.1.4.1.1.1.2.1" => ACQUISITION_CONTEXT, // Digital Mammography For Processing
+    "1.2.840.10008.5.1.4.1.1.1.3" => ACQUISITION_CONTEXT, // Digital Intra-Oral X-Ray For Presentation
+    "1.2.840.10008.5.1.4.1.1.1.3.1" => ACQUISITION_CONTEXT, // Digital Intra-Oral X-Ray For Processing
+    "1.2.840.10008.5.1.4.1.1.77.1.1" => ACQUISITION_CONTEXT, // VL Endoscopic
+    "1.2.840.10008.5.1.4.1.1.77.1.2" => ACQUISITION_CONTEXT, // VL Microscopic
+    "1.2.840.10008.5.1.4.1.1.77.1.3" => ACQUISITION_CONTEXT, // VL Slide-Coordinates Microscopic
+    "1.2.840.10008.5.1.4.1.1.77.1.4" => ACQUISITION_CONTEXT, // VL Photographic
+    "1.2.840.10008.5.1.4.1.1.77.1.5.1" => ACQUISITION_CONTEXT, // Ophthalmic Photography 8 Bit
+    "1.2.840.10008.5.1.4.1.1.77.1.5.2" => ACQUISITION_CONTEXT, // Ophthalmic Photography 16 Bit
+    "1.2.840.10008.5.1.4.1.1.77.1.5.4" => ACQUISITION_CONTEXT, // Ophthalmic Tomography
+    "1.2.840.10008.5.1.4.1.1.88.11" => SR_DOCUMENT, // Basic Text SR
+    "1.2.840.10008.5.1.4.1.1.88.22" => SR_DOCUMENT, // Enhanced SR
+    "1.2.840.10008.5.1.4.1.1.88.33" => SR_DOCUMENT, // Comprehensive SR
+    "1.2.840.10008.5.1.4.1.1.88.34" => SR_DOCUMENT, // Comprehensive 3D SR
+    "1.2.840.10008.5.1.4.1.1.88.35" => SR_DOCUMENT, // Extensible SR
+    "1.2.840.10008.5.1.4.1.1.88.40" => SR_DOCUMENT, // Procedure Log
+    "1.2.840.10008.5.1.4.1.1.88.50" => SR_DOCUMENT, // Mammography CAD SR
+    "1.2.840.10008.5.1.4.1.1.88.59" => SR_DOCUMENT, // Key Object Selection Document
+    "1.2.840.10008.5.1.4.1.1.88.65" => SR_DOCUMENT, // Chest CAD SR
+    "1.2.840.10008.5.1.4.1.1.88.67" => SR_DOCUMENT, // X-Ray Radiation Dose SR
+    "1.2.840.10008.5.1.4.1.1.88.68" => SR_DOCUMENT, // Radiopharmaceutical Radiation Dose SR
+    "1.2.840.10008.5.1.4.1.1.88.69" => SR_DOCUMENT, // Colon CAD SR
+    "1.2.840.10008.5.1.4.1.1.88.70" => SR_DOCUMENT, // Implantation Plan SR
+    "1.2.840.10008.5.1.4.1.1.88.71" => SR_DOCUMENT, // Acquisition Context SR
+    "1.2.840.10008.5.1.4.1.1.88.72" => SR_DOCUMENT, // Simplified Adult Echo SR
+    "1.2.840.10008.5.1.4.1.1.88.73" => SR_DOCUMENT, // Patient Radiation Dose SR
+    "1.2.840.10008.5.1.4.1.1.88.74" => SR_DOCUMENT, // Planned Imaging Agent Administration SR
+    "1.2.840.10008.5.1.4.1.1.88.75" => SR_DOCUMENT, // Performed Imaging Agent Administration SR
+    "1.2.840.10008.5.1.4.1.1.88.76" => SR_DOCUMENT, // Enhanced X-Ray Radiation Dose SR
+};
+
+// Request Attributes macro
+const REQUEST_ATTRIBUTES: Types = &[
+    ((0x0032, 0x1060), Requirement::Type3), // Requested Procedure Description
+];
+
+/// Attribute types of the items of a sequence, by sequence tag.
+/// The IOD types only apply to the top level of the dataset.
+static ITEM_TYPES: phf::Map<(u16, u16), Types> = phf_map! {
+    (0x0040u16, 0x0275u16) => REQUEST_ATTRIBUTES, // Request Attributes Sequence
+};
+
+/// Attribute types of one file, used to resolve the compound actions (`ZD`, `XZ`, `XD`, `XZD`, `XZU`).
+///
+/// Inside sequence items the types of the innermost sequence are used, see `Requirements::within`.
+#[derive(Debug)]
+pub struct Requirements {
+    iod: Types,
+    animal: bool,
+    reviewed: bool,
+    original: bool,
+    /// Sequence whose items are being de-identified, `None` at the top level
+    sequence: Cell<Option<Tag>>,
+}
+
+/// Restores the enclosing sequence when dropped
+pub struct SequenceScope<'a> {
+    requirements: &'a Requirements,
+    previous: Option<Tag>,
+}
+
+impl Drop for SequenceScope<'_> {
+    fn drop(&mut self) {
+        self.requirements.sequence.set(self.previous);
+    }
+}
+
+impl Requirements {
+    /// Must be built before any modification of the dataset
+    pub fn new(data: &InMemDicomObject) -> Self {
+        let sop_class = data
+            .get(tags::SOP_CLASS_UID)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim_end_matches(['\0', ' ']).to_string())
+            .unwrap_or_default();
+        let approval = data
+            .get(tags::APPROVAL_STATUS)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim().to_uppercase())
+            .unwrap_or_default();
+        let image_type = data
+            .get(tags::IMAGE_TYPE)
+            .and_then(|e| e.to_multi_str().ok())
+            .and_then(|v| v.first().map(|s| s.trim().to_uppercase()))
+            .unwrap_or_default();
+
+        Self {
+            iod: IOD_TYPES.get(sop_class.as_str()).copied().unwrap_or_default(),
+            animal: data.get(tags::PATIENT_SPECIES_DESCRIPTION).is_some()
+                || data.get(tags::PATIENT_SPECIES_CODE_SEQUENCE).is_some(),
+            reviewed: approval == "APPROVED" || approval == "REJECTED",
+            original: image_type == "ORIGINAL" || image_type == "MIXED",
+            sequence: Cell::new(None),
+        }
+    }
+
+    /// Resolve the types of the items of `sequence` until the scope is dropped
+    pub fn within(&self, sequence: Tag) -> SequenceScope<'_> {
+        SequenceScope {
+            requirements: self,
+            previous: self.sequence.replace(Some(sequence)),
+        }
+    }
+
+    pub fn of(&self, tag: Tag) -> Requirement {
+        let key = (tag.group(), tag.element());
+        let find = |types: Types| types.iter().find(|(k, _)| *k == key).map(|(_, r)| *r);
+
+        if let Some(sequence) = self.sequence.get() {
+            return ITEM_TYPES
+                .get(&(sequence.group(), sequence.element()))
+                .and_then(|types| find(types))
+                .unwrap_or(Requirement::Type2);
+        }
+
+        let base = find(self.iod)
+            .or_else(|| DEFAULT_TYPES.get(&key).copied())
+            .unwrap_or(Requirement::Type2);
+
+        match tag {
+            // 2C: required if the patient is an animal
+            tags::PATIENT_SEX_NEUTERED if self.animal => Requirement::Type2,
+            // 2C: required if the Approval Status is APPROVED or REJECTED
+            tags::REVIEWER_NAME if self.reviewed => Requirement::Type2,
+            // 1C: required if the Image Type Value 1 is ORIGINAL or MIXED
+            tags::ACQUISITION_DATE_TIME if !self.original => Requirement::Type3,
+            _ => base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::{DataElement, PrimitiveValue, VR};
+
+    fn requirements(sop_class: &str) -> Requirements {
+        Requirements::new(&InMemDicomObject::from_element_iter([DataElement::new(
+            tags::SOP_CLASS_UID,
+            VR::UI,
+            PrimitiveValue::from(sop_class),
+        )]))
+    }
+
+    #[test]
+    fn iod_types() {
+        // CT Image
+        let ct = requirements("1.2.840.10008.5.1.4.1.1.2");
+        assert_eq!(ct.of(tags::DEVICE_SERIAL_NUMBER), Requirement::Type3);
+        assert_eq!(ct.of(tags::CONTENT_DATE), Requirement::Type2);
+        // Enhanced CT
+        let enhanced = requirements("1.2.840.10008.5.1.4.1.1.2.1");
+        assert_eq!(enhanced.of(tags::DEVICE_SERIAL_NUMBER), Requirement::Type1);
+        assert_eq!(enhanced.of(tags::STATION_NAME), Requirement::Type3);
+    }
+
+    #[test]
+    fn unknown_attributes_keep_a_value() {
+        let ct = requirements("1.2.840.10008.5.1.4.1.1.2");
+        assert_eq!(ct.of(tags::DETECTOR_TYPE), Requirement::Type2);
+    }
+
+    #[test]
+    fn sequence_items_use_their_own_types() {
+        let enhanced = requirements("1.2.840.10008.5.1.4.1.1.2.1");
+        {
+            let _scope = enhanced.within(tags::REQUEST_ATTRIBUTES_SEQUENCE);
+            assert_eq!(enhanced.of(tags::REQUESTED_PROCEDURE_DESCRIPTION), Requirement::Type3);
+            // The IOD types don't apply inside items
+            assert_eq!(enhanced.of(tags::DEVICE_SERIAL_NUMBER), Requirement::Type2);
+            {
+                let _nested = enhanced.within(tags::SOURCE_IMAGE_SEQUENCE);
+                assert_eq!(enhanced.of(tags::REQUESTED_PROCEDURE_DESCRIPTION), Requirement::Type2);
+            }
+            assert_eq!(enhanced.of(tags::REQUESTED_PROCEDURE_DESCRIPTION), Requirement::Type3);
+        }
+        assert_eq!(enhanced.of(tags::DEVICE_SERIAL_NUMBER), Requirement::Type1);
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use pulsedcm_core::{
+    DataDictionary, InMemDicomObject, PrimitiveValue, PulseError, PulseErrorKind, Result,
+    StandardDataDictionary, Tag, VR,
+};
+use serde::Deserialize;
+
+use crate::models::{ActionCode, DeidContext};
+
+/// Hex digits of a keyed hash rendered by `@hash(...)`
+const HASH_LEN: usize = 16;
+
+/// Site-specific profile loaded from a TOML file, on top of the built-in table.
+///
+/// ```toml
+/// [[rule]]
+/// tag = "(50xx,xxxx)"
+/// action = "X"
+///
+/// [[rule]]
+/// tag = "PatientName"
+/// value = "SUBJ^@hash(PatientID)"
+/// ```
+#[derive(Debug, Default)]
+pub struct CustomProfile {
+    exact: HashMap<Tag, Rule>,
+    masked: Vec<(TagMask, Rule)>,
+    // Attributes referenced by the templates, read before the dataset is modified
+    referenced: Vec<Tag>,
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    action: ActionCode,
+    value: Option<Template>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileFile {
+    #[serde(default)]
+    rule: Vec<RuleEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleEntry {
+    tag: String,
+    action: Option<String>,
+    value: Option<String>,
+}
+
+impl CustomProfile {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = read_to_string(path)?;
+        content.parse().map_err(|e: String| {
+            PulseError::new(
+                PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidData, e)),
+                format!("Invalid custom profile {}", path.display()),
+            )
+        })
+    }
+
+    /// Exact tags take over masks, masks are tried in the order of the file
+    pub fn get(&self, tag: Tag) -> Option<&Rule> {
+        self.exact.get(&tag).or_else(|| {
+            self.masked
+                .iter()
+                .find(|(mask, _)| mask.matches(tag))
+                .map(|(_, rule)| rule)
+        })
+    }
+
+    /// Original values of the attributes used by the templates
+    pub fn template_values(&self, data: &InMemDicomObject) -> HashMap<Tag, String> {
+        self.referenced
+            .iter()
+            .filter_map(|tag| {
+                let value = data.get(*tag)?.to_str().ok()?.trim().to_string();
+                Some((*tag, value))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for CustomProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let file: ProfileFile = toml::from_str(s).map_err(|e| e.to_string())?;
+        let mut profile = CustomProfile::default();
+        for entry in file.rule {
+            let mask: TagMask = entry.tag.parse()?;
+            let value = entry.value.as_deref().map(Template::from_str).transpose()?;
+            // Actions are parsed in any case, a replacement value being a dummy of our own
+            let action = entry.action.as_deref().map(ActionCode::from_str).transpose()?;
+            let action = match (action, &value) {
+                (Some(action), None) => action,
+                (Some(ActionCode::D) | None, Some(_)) => ActionCode::D,
+                (Some(_), Some(_)) => {
+                    return Err(format!(
+                        "Rule {}: a value only goes with the D action, not {}",
+                        entry.tag,
+                        entry.action.as_deref().unwrap_or_default()
+                    ))
+                }
+                (None, None) => return Err(format!("Rule {}: expected an action or a value", entry.tag)),
+            };
+            if let Some(template) = &value {
+                profile.referenced.extend(template.referenced());
+            }
+
+            let rule = Rule { action, value };
+            match mask.exact() {
+                Some(tag) => {
+                    profile.exact.insert(tag, rule);
+                }
+                None => profile.masked.push((mask, rule)),
+            }
+        }
+        profile.referenced.sort();
+        profile.referenced.dedup();
+        Ok(profile)
+    }
+}
+
+impl Rule {
+    pub fn process(&self, data: &mut InMemDicomObject, tag: &Tag, vr: &VR, ctx: &DeidContext) -> Result<()> {
+        let Some(template) = self.value.as_ref().filter(|_| is_text(vr)) else {
+            // Numeric and binary attributes get the usual dummy value
+            return self.action.process(data, tag, vr, ctx);
+        };
+        ctx.trail.record(ctx.uids, data.get(*tag), *tag, "D");
+        let value = template.render(ctx);
+        data.update_value_at(*tag, |v| {
+            if let Some(val) = v.primitive_mut() {
+                *val = PrimitiveValue::from(value.as_str());
+            }
+        })?;
+        Ok(())
+    }
+}
+
+/// `(gggg,eeee)` where any hex digit can be an `x`, or an attribute keyword
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TagMask {
+    group: (u16, u16),   // (value, mask)
+    element: (u16, u16), // (value, mask)
+}
+
+impl TagMask {
+    fn matches(&self, tag: Tag) -> bool {
+        tag.group() & self.group.1 == self.group.0 && tag.element() & self.element.1 == self.element.0
+    }
+
+    fn exact(&self) -> Option<Tag> {
+        (self.group.1 == 0xFFFF && self.element.1 == 0xFFFF).then_some(Tag(self.group.0, self.element.0))
+    }
+}
+
+impl FromStr for TagMask {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = s
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .or_else(|| s.strip_prefix('[').and_then(|t| t.strip_suffix(']')));
+        let Some((group, element)) = inner.and_then(|t| t.split_once(',')) else {
+            let tag = StandardDataDictionary
+                .by_name(s)
+                .map(|e| e.tag.inner())
+                .ok_or_else(|| format!("Unknown attribute '{}'", s))?;
+            return Ok(Self {
+                group: (tag.group(), 0xFFFF),
+                element: (tag.element(), 0xFFFF),
+            });
+        };
+        let part = |p: &str| -> std::result::Result<(u16, u16), String> {
+            let p = p.trim();
+            if p.len() != 4 {
+                return Err(format!("Invalid tag '{}', expected (gggg,eeee)", s));
+            }
+            let (mut value, mut mask) = (0u16, 0u16);
+            for c in p.chars() {
+                let (v, m) = match c {
+                    'x' | 'X' => (0, 0),
+                    c => (
+                        c.to_digit(16).ok_or_else(|| format!("Invalid tag '{}', expected (gggg,eeee)", s))? as u16,
+                        0xF,
+                    ),
+                };
+                value = value << 4 | v;
+                mask = mask << 4 | m;
+            }
+            Ok((value, mask))
+        };
+        Ok(Self {
+            group: part(group)?,
+            element: part(element)?,
+        })
+    }
+}
+
+/// Replacement value, constant text with `@hash(Keyword)` parts
+#[derive(Debug, PartialEq, Eq)]
+struct Template(Vec<Part>);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Hash(Tag), // Keyed hash of the original value of an attribute
+}
+
+impl Template {
+    fn referenced(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.0.iter().filter_map(|p| match p {
+            Part::Hash(tag) => Some(*tag),
+            Part::Text(_) => None,
+        })
+    }
+
+    fn render(&self, ctx: &DeidContext) -> String {
+        self.0
+            .iter()
+            .map(|p| match p {
+                Part::Text(text) => text.clone(),
+                Part::Hash(tag) => {
+                    let original = ctx.template_values.get(tag).map(String::as_str).unwrap_or_default();
+                    ctx.uids.digest(original)[..HASH_LEN / 2]
+                        .iter()
+                        .map(|b| format!("{:02X}", b))
+                        .collect()
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find("@hash(") {
+            let end = rest[start..]
+                .find(')')
+                .map(|e| start + e)
+                .ok_or_else(|| format!("Unclosed @hash( in '{}'", s))?;
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_string()));
+            }
+            let keyword = rest[start + "@hash(".len()..end].trim();
+            let mask: TagMask = keyword.parse()?;
+            let tag = mask.exact().ok_or_else(|| format!("@hash needs a single attribute, not '{}'", keyword))?;
+            parts.push(Part::Hash(tag));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        Ok(Self(parts))
+    }
+}
+
+fn is_text(vr: &VR) -> bool {
+    matches!(
+        vr,
+        VR::AE | VR::AS | VR::CS | VR::DA | VR::DS | VR::DT | VR::IS | VR::LO | VR::LT
+            | VR::PN | VR::SH | VR::ST | VR::TM | VR::UC | VR::UI | VR::UR | VR::UT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::dicom_dictionary_std::tags;
+
+    const PROFILE: &str = r#"
+        [[rule]]
+        tag = "(50xx,xxxx)"
+        action = "X"
+
+        [[rule]]
+        tag = "(0009,10XX)"
+        action = "x/z/d"
+
+        [[rule]]
+        tag = "PatientName"
+        value = "SUBJ^@hash(PatientID)"
+
+        [[rule]]
+        tag = "(0008,0080)"
+        action = "K"
+    "#;
+
+    #[test]
+    fn parse_rules() {
+        let profile: CustomProfile = PROFILE.parse().unwrap();
+        assert_eq!(profile.exact.len(), 2);
+        assert_eq!(profile.masked.len(), 2);
+        assert_eq!(profile.referenced, [tags::PATIENT_ID]);
+
+        assert!(matches!(profile.get(Tag(0x5002, 0x3000)).unwrap().action, ActionCode::X));
+        assert!(matches!(profile.get(Tag(0x0009, 0x10AB)).unwrap().action, ActionCode::XZD));
+        assert!(profile.get(Tag(0x0009, 0x11AB)).is_none());
+        assert!(matches!(profile.get(tags::INSTITUTION_NAME).unwrap().action, ActionCode::K));
+        let name = profile.get(tags::PATIENT_NAME).unwrap();
+        assert!(matches!(name.action, ActionCode::D));
+        assert_eq!(
+            name.value,
+            Some(Template(vec![Part::Text("SUBJ^".into()), Part::Hash(tags::PATIENT_ID)]))
+        );
+    }
+
+    #[test]
+    fn parse_actions_in_any_case() {
+        let profile: CustomProfile = r#"
+            [[rule]]
+            tag = "PatientName"
+            action = "d"
+            value = "Anonymous"
+
+            [[rule]]
+            tag = "PatientID"
+            action = "x/z/d"
+        "#
+        .parse()
+        .unwrap();
+        assert!(matches!(profile.get(tags::PATIENT_NAME).unwrap().action, ActionCode::D));
+        assert!(matches!(profile.get(tags::PATIENT_ID).unwrap().action, ActionCode::XZD));
+    }
+
+    #[test]
+    fn parse_masks() {
+        let mask: TagMask = "(50xx,XXXX)".parse().unwrap();
+        assert!(mask.matches(Tag(0x50FF, 0x0001)));
+        assert!(!mask.matches(Tag(0x5100, 0x0001)));
+        assert_eq!(mask.exact(), None);
+        assert_eq!("[0010,0010]".parse::<TagMask>().unwrap().exact(), Some(tags::PATIENT_NAME));
+        assert_eq!("PatientID".parse::<TagMask>().unwrap().exact(), Some(tags::PATIENT_ID));
+    }
+
+    #[test]
+    fn reject_invalid_rules() {
+        for (rule, error) in [
+            (r#"tag = "(50x,xxxx)""#, "Invalid tag"),
+            (r#"tag = "(50xx,xxxg)""#, "Invalid tag"),
+            (r#"tag = "PatientNom""#, "Unknown attribute 'PatientNom'"),
+            (r#"tag = "PatientName"
+                value = "@hash(PatientNom)""#, "Unknown attribute 'PatientNom'"),
+            (r#"tag = "PatientName"
+                value = "@hash(50xx,xxxx)""#, "Unknown attribute"),
+            (r#"tag = "PatientName"
+                value = "@hash(PatientID""#, "Unclosed @hash("),
+            (r#"tag = "PatientName"
+                action = "X"
+                value = "Anonymous""#, "a value only goes with the D action"),
+            (r#"tag = "PatientName""#, "expected an action or a value"),
+            (r#"tag = "PatientName"
+                action = "Y""#, ""),
+            (r#"tag = "PatientName"
+                actions = "X""#, "unknown field"),
+        ] {
+            let profile = format!("[[rule]]\n{}\n", rule);
+            let err = profile.parse::<CustomProfile>().unwrap_err();
+            assert!(err.contains(error), "{}: {}", rule, err);
+        }
+    }
+
+    #[test]
+    fn render_templates() {
+        let template: Template = "ID-@hash(PatientID)-@hash(StudyInstanceUID)".parse().unwrap();
+        assert_eq!(template.referenced().collect::<Vec<_>>(), [tags::PATIENT_ID, tags::STUDY_INSTANCE_UID]);
+        assert_eq!("plain".parse::<Template>().unwrap(), Template(vec![Part::Text("plain".into())]));
+    }
+}
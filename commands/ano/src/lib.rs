@@ -1,123 +1,518 @@
-use std::{ os::unix::fs::OpenOptionsExt, path::PathBuf};
+use std::path::PathBuf;
 
+pub mod audit;
+pub mod clean;
+pub mod conformance;
+pub mod custom;
+pub mod method;
 pub mod models;
+pub mod output;
+pub mod private;
+pub mod profile;
+pub mod pseudonym;
+pub mod redaction;
+pub mod uid;
+pub mod verify;
 // pub use models;
 use pulsedcm_core::*;
 
-use crate::models::DEID_HASH;
+use crate::audit::{AuditLog, FileTrail, Mapping};
+use crate::clean::{Cleaner, PatientCleaner};
+use crate::conformance::Requirements;
+use crate::custom::CustomProfile;
+use crate::models::{DeidContext, DEID_HASH};
+use crate::output::{OutputLayout, PathTemplate};
+use crate::private::{PrivateMode, PrivateTags};
+use crate::profile::{ActionOverride, Policy, Profile};
+use crate::pseudonym::{PseudonymSource, Pseudonymizer};
+use crate::redaction::RedactionRules;
+use crate::uid::UidGenerator;
+use crate::verify::{verify_file, Original, Verification};
 
-pub fn threading_handling(
-    files: Vec<PathBuf>, 
-    output_path: PathBuf,
-    dry: &mut bool, 
+/// Options of an ano run, as given on the command line
+#[derive(Debug)]
+pub struct AnoConfig {
+    pub input_path: PathBuf,
+    pub output_path: PathBuf,
+    pub dry: bool,
+    pub with_pixel_data: bool,
+    pub jobs: usize,
+    pub verbose: bool,
+    pub uid_secret: Option<String>,
+    pub policy: Policy,
+    pub profile: Profile,
+    pub action: Option<ActionOverride>,
+    pub clean_patterns: Option<PathBuf>,
+    pub private_mode: Option<PrivateMode>,
+    pub redact_rules: Option<PathBuf>,
+    pub audit_path: Option<PathBuf>,
+    pub mapping_path: Option<PathBuf>,
+    pub pseudonyms: Option<PseudonymSource>,
+    pub verify: bool,
+    pub verify_report: Option<PathBuf>,
+    pub custom_profile: Option<PathBuf>,
+    pub name_template: Option<PathTemplate>,
+    pub backup: bool,
+    pub journal_path: Option<PathBuf>,
+    pub resume: bool,
+    pub confirmation: Confirmation,
+}
+
+/// Run-wide state shared by the threads, built once from the `AnoConfig`
+struct Run {
+    layout: OutputLayout,
+    verbose: bool,
+    dry: bool,
     with_pixel_data: bool,
-    jobs: usize,
-    verbose: bool, 
-    ) -> Result<()> {
-    
-    println!("Dry?: {}", dry);
+    uids: UidGenerator,
+    profile: Profile,
+    cleaner: Cleaner,
+    private: PrivateTags,
+    redaction: Option<RedactionRules>,
+    pseudonymizer: Option<Pseudonymizer>,
+    custom: Option<CustomProfile>,
+    audit: Option<AuditLog>,
+    mapping: Option<Mapping>,
+    verification: Option<Verification>,
+}
+
+pub fn threading_handling(files: Vec<PathBuf>, config: AnoConfig, summary: &RunSummary) -> Result<()> {
+    // Options given explicitly come on top of the policy preset
+    let mut profile = config.policy.profile().merge(&config.profile);
+    if config.action.is_some() {
+        profile.action = config.action;
+    }
+    if profile.ret_lg_mod_dt {
+        // Shifts must stay the same for a patient from one run to the next
+        require_secret(config.uid_secret.as_deref(), "Modified dates (retain-long-modified-dates)")?;
+    }
+    if config.audit_path.is_some() || config.mapping_path.is_some() {
+        // Hashes and UIDs must be checkable against the audit and the mapping afterwards
+        require_secret(config.uid_secret.as_deref(), "--audit and --mapping")?;
+    }
+    if config.pseudonyms == Some(PseudonymSource::Hash) {
+        // A patient must get the same subject ID in a later run
+        require_secret(config.uid_secret.as_deref(), "Hashed pseudonyms (--pseudonyms hash)")?;
+    }
+    // Same generator for all threads so a UID is remapped the same way in every file
+    let uids = match config.uid_secret {
+        Some(secret) => UidGenerator::new(secret),
+        None => UidGenerator::random()?,
+    };
+    let cleaner = match config.clean_patterns {
+        Some(path) => Cleaner::from_file(&path)?,
+        None => Cleaner::default(),
+    };
+    // Safe private attributes are kept by default only with the Retain Safe Private Option
+    let private = PrivateTags::new(config.private_mode.unwrap_or(if profile.ret_sf_priv {
+        PrivateMode::KeepSafe
+    } else {
+        PrivateMode::Remove
+    }));
+    let redaction = config.redact_rules
+        .map(|path| RedactionRules::from_file(&path))
+        .transpose()?;
+    let audit = config.audit_path
+        .as_deref()
+        .map(|path| AuditLog::create(path, config.resume))
+        .transpose()?;
+    let mapping = config.mapping_path
+        .as_deref()
+        .map(|path| Mapping::open(path, config.resume))
+        .transpose()?;
+    let input_path = config.input_path.clone();
+    let journal_dir = config.output_path.clone();
+    let run = Run {
+        // Redaction needs the pixel data
+        with_pixel_data: config.with_pixel_data || redaction.is_some(),
+        redaction,
+        audit,
+        mapping,
+        pseudonymizer: config.pseudonyms
+            .as_ref()
+            .map(Pseudonymizer::new)
+            .transpose()?,
+        custom: config.custom_profile
+            .map(|path| CustomProfile::from_file(&path))
+            .transpose()?,
+        verification: (config.verify || config.verify_report.is_some()).then(Verification::default),
+        layout: OutputLayout::new(config.input_path, config.output_path, config.name_template, config.confirmation, config.backup),
+        verbose: config.verbose,
+        dry: config.dry,
+        uids,
+        profile,
+        cleaner,
+        private,
+    };
     let thread_pool = rayon::ThreadPoolBuilder::new()
-        .num_threads(jobs)
+        .num_threads(config.jobs)
         .build()?;
 
-    if let Some((first, rest)) = files.split_first(){
-        if *dry {
-            single_thread_process(first.into(), &mut output_path.clone(),verbose ,dry, with_pixel_data)?;
+    if run.dry {
+        // A dry run only shows what the first file would become
+        if let Some(first) = files.first() {
+            summary.record(first, single_thread_process(first.into(), &run));
+        }
+    } else {
+        if run.layout.in_place() && !config.confirmation.overwrite_inputs(files.len(), summary)? {
+            return Ok(());
         }
-        *dry = false;
-
-        let _ = thread_pool.install(|| {
-            let _ = rest.par_iter().try_for_each(
-                |file: &PathBuf| -> Result<()> {
-                    single_thread_process(file.into(), &mut output_path.clone(), verbose , dry, with_pixel_data)?;
-                    Ok(())
-                });
+
+        // Overwritten inputs get their journal out of the input tree
+        let journal = match config.journal_path {
+            Some(path) => Journal::open(path, config.resume)?,
+            None if run.layout.in_place() => Journal::in_cache(&input_path, "ano", config.resume)?,
+            None => {
+                if !journal_dir.exists() {
+                    create_dir_all(&journal_dir)?;
+                }
+                Journal::for_output(&journal_dir, "ano", config.resume)?
+            }
+        };
+        if config.verbose {
+            println!("Journal: {}", journal.path().display());
+        }
+        let files = journal.pending(files, summary)?;
+
+        // A failed file doesn't stop the others, every outcome going to the summary
+        thread_pool.install(|| {
+            files.par_iter().for_each(|file: &PathBuf| {
+                let outcome = single_thread_process(file.into(), &run)
+                    .and_then(|_| journal.complete(file));
+                summary.record(file, outcome);
+            });
         });
     }
+    run.private.print_report();
+    if let Some(audit) = run.audit {
+        audit.flush()?;
+    }
+    if let (Some(mapping), Some(path)) = (run.mapping, config.mapping_path) {
+        mapping.write(&path)?;
+    }
+    if let Some(verification) = run.verification {
+        verification.print_summary();
+        if let Some(path) = config.verify_report {
+            verification.write(&path)?;
+        }
+        let failed = verification.failed();
+        if failed > 0 {
+            return Err(PulseError::new(
+                PulseErrorKind::IO(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "PHI left in the output files",
+                )),
+                format!("{} file(s) failed the verification", failed),
+            ));
+        }
+    }
 
     Ok(())
 }
 
-pub fn single_thread_process(
-    input_path: PathBuf,
-    output_path: &mut PathBuf,
-    verbose: bool,
-    dry: &bool,
-    with_pixel_data: bool
-) -> Result<()> {
-    let data = de_identify_file(input_path.clone(), with_pixel_data, verbose)?; 
+fn single_thread_process(input_path: PathBuf, run: &Run) -> Result<()> {
+    let (data, trail) = de_identify_file(input_path.clone(), run)?; 
 
-    if *dry {
-        if verbose {
+    if run.dry {
+        if run.verbose {
             println!("Launching a dry run");
         }
         print_tags(&data);
         return Ok(());
     }
-    println!("testing, {}", dry);
-    let filename = input_path.file_name().unwrap_or_default();
+    let output_path = run.layout.destination(&input_path, &data)?;
+    // Read before the source can be overwritten
+    let original = run.verification.as_ref().map(|_| Original::open(&input_path)).transpose()?;
+    run.layout.write(&data, &output_path)?;
+    record_trail(&input_path, &output_path, &trail, run.audit.as_ref(), run.mapping.as_ref())?;
+    if let (Some(verification), Some(original)) = (run.verification.as_ref(), original) {
+        verification.add(verify_file(&original, &output_path)?, run.verbose)?;
+    }
+    Ok(())
+}
 
-    // Case where out is not specified
-    if input_path.to_str().unwrap_or_default() == output_path.as_os_str().to_str().unwrap_or_default() {
-        if ask_yes_no("? No output_path specified confirm to overwrite actual files") {
-            output_path.push(filename);
-            data.write_to_file(&output_path)?;
-        } else {
-            println!("Stopping...");
-            return Ok(());
-        }
-    } else {
-        if !output_path.is_dir() {
-            eprintln!("Output path shouldn't be a file");
-            return Ok(());
-        }
-        output_path.push(filename);
-        data.write_to_file(&output_path)?;
+/// Only written files get into the audit and the mapping
+fn record_trail(
+    input_path: &Path,
+    output_path: &Path,
+    trail: &FileTrail,
+    audit: Option<&AuditLog>,
+    mapping: Option<&Mapping>,
+) -> Result<()> {
+    if let Some(audit) = audit {
+        audit.write_file(input_path, output_path, &trail.records())?;
+    }
+    if let Some(mapping) = mapping {
+        mapping.extend(trail)?;
     }
     Ok(())
 }
 
-fn de_identify_file (
-    file_path: PathBuf, 
-    // profile: Profile :TODO: Later implement the profile to match the right 
-    // one in policyAction
-    with_pixel_data: bool,
-    verbose: bool
-) -> Result<FileDicomObject<InMemDicomObject>> {
-    
-    let mut data = if !with_pixel_data {
+fn de_identify_file(file_path: PathBuf, run: &Run) -> Result<(FileDicomObject<InMemDicomObject>, FileTrail)> {
+    let (uids, profile, custom) = (&run.uids, &run.profile, run.custom.as_ref());
+    let mut data = if !run.with_pixel_data {
         OpenFileOptions::new()
             .read_until(dicom_dictionary_std::tags::PIXEL_DATA)
-            .open_file(file_path)?
+            .open_file(&file_path)?
     } else {
-        open_file(file_path)?
+        open_file(&file_path)?
     };
 
-    for (key, value) in DEID_HASH.entries() {
-        let rec_tag: Tag = Tag{0: key.0, 1:key.1};
-        // Check if in 
-        if let Ok(elem) = data.element(rec_tag) {
-            let vr = elem.vr();
-            value.basic.process(&mut data, &rec_tag, &vr)?;
-        } else {
+    let trail = FileTrail::default();
+
+    // Signatures are matched on the original attributes
+    let mut pixels_cleaned = false;
+    if let Some(rules) = run.redaction.as_ref() {
+        match rules.regions(&data) {
+            Some(regions) => {
+                pixels_cleaned = true;
+                if !regions.is_empty() {
+                    trail.record(uids, data.get(dicom_dictionary_std::tags::PIXEL_DATA), dicom_dictionary_std::tags::PIXEL_DATA, "C");
+                    crate::redaction::redact(&mut data, regions)?;
+                }
+                data.put_str(dicom_dictionary_std::tags::BURNED_IN_ANNOTATION, VR::CS, "NO");
+            }
+            None => {
+                if run.verbose {
+                    println!("No redaction rule matching {}", file_path.display());
+                }
+            }
+        }
+    }
+
+    // Built from the untouched dataset, before the patient attributes are removed
+    let ctx = DeidContext {
+        uids,
+        cleaner: PatientCleaner::new(&run.cleaner, uids, &data),
+        requirements: Requirements::new(&data),
+        private: &run.private,
+        trail,
+        pseudonym: run.pseudonymizer.as_ref().map(|p| p.pseudonym(uids, &data)).transpose()?,
+        template_values: custom.map(|c| c.template_values(&data)).unwrap_or_default(),
+    };
+    let patient_id = patient_id(&data);
+    de_identify_dataset(&mut data, profile, custom, &ctx)?;
+    crate::method::mark_deidentified(&mut data, profile, run.private.mode(), pixels_cleaned, custom.is_some());
+    // An emptied PatientID has no pseudonym to map to
+    if let (Some(original), Some(new)) = (patient_id, self::patient_id(&data)) {
+        ctx.trail.map("PatientID", &original, &new);
+    }
+    Ok((data, ctx.trail))
+}
+
+/// Values derived from the run secret are only reproducible with `--uid-secret`
+fn require_secret(uid_secret: Option<&str>, what: &str) -> Result<()> {
+    if uid_secret.is_some() {
+        return Ok(());
+    }
+    Err(PulseError::new(
+        PulseErrorKind::IO(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Missing --uid-secret",
+        )),
+        format!("{} need --uid-secret, a random secret would change them on every run", what),
+    ))
+}
+
+fn is_patient_identity(tag: Tag) -> bool {
+    tag == dicom_dictionary_std::tags::PATIENT_ID || tag == dicom_dictionary_std::tags::PATIENT_NAME
+}
+
+fn patient_id(data: &InMemDicomObject) -> Option<String> {
+    data.get(dicom_dictionary_std::tags::PATIENT_ID)
+        .and_then(|e| e.to_str().ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Apply the private mode, the custom rules and the policy table to every element of the dataset, then walk
+/// the items of each remaining sequence the same way, at any depth.
+fn de_identify_dataset(
+    data: &mut InMemDicomObject,
+    profile: &Profile,
+    custom: Option<&CustomProfile>,
+    ctx: &DeidContext,
+) -> Result<()> {
+    for elem in ctx.private.process(data) {
+        ctx.trail.record(ctx.uids, Some(&elem), elem.header().tag, "X");
+    }
+
+    let elements: Vec<(Tag, VR)> = data
+        .iter()
+        .map(|elem| (elem.header().tag, elem.vr()))
+        .collect();
+
+    for (tag, vr) in elements {
+        if let Some(rule) = custom.and_then(|c| c.get(tag)) {
+            // Site rules take over everything else
+            rule.process(data, &tag, &vr, ctx)?;
+        } else if let Some(pseudonym) = ctx.pseudonym.as_ref().filter(|_| is_patient_identity(tag)) {
+            // Replaced by the patient's pseudonym, at every level so the file stays consistent
+            ctx.trail.record(ctx.uids, data.get(tag), tag, "D");
+            let value = if tag == dicom_dictionary_std::tags::PATIENT_ID { &pseudonym.id } else { &pseudonym.name };
+            data.put_str(tag, vr, value.as_str());
+        } else if let Some(value) = DEID_HASH.get(&(tag.group(), tag.element())) {
+            // Options enabled in the profile take over the basic action
+            profile.resolve(value).process(data, &tag, &vr, ctx)?;
+        }
+
+        // Removed or emptied sequences have nothing left to walk through
+        let is_sequence = data
+            .get(tag)
+            .and_then(|elem| elem.items())
+            .is_some_and(|items| !items.is_empty());
+        if !is_sequence {
             continue;
         }
+
+        let mut res: Result<()> = Ok(());
+        let _scope = ctx.requirements.within(tag);
+        data.update_value_at(tag, |v| {
+            if let Some(items) = v.items_mut() {
+                for item in items.iter_mut() {
+                    if res.is_ok() {
+                        res = de_identify_dataset(item, profile, custom, ctx);
+                    }
+                }
+            }
+        })?;
+        res?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::dicom_dictionary_std::tags;
+
+    fn item(elements: Vec<InMemElement>) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter(elements)
+    }
+
+    fn sequence(tag: Tag, items: Vec<InMemDicomObject>) -> InMemElement {
+        DataElement::new(tag, VR::SQ, Value::from(DataSetSequence::from(items)))
+    }
+
+    fn patient() -> Vec<InMemElement> {
+        vec![
+            DataElement::new(tags::PATIENT_NAME, VR::PN, PrimitiveValue::from("Doe^John")),
+            DataElement::new(tags::PATIENT_ADDRESS, VR::LO, PrimitiveValue::from("1 Main Street")),
+        ]
+    }
+
+    fn de_identify(data: &mut InMemDicomObject, profile: &Profile) {
+        let uids = UidGenerator::new("secret");
+        let cleaner = Cleaner::default();
+        let private = PrivateTags::new(PrivateMode::Remove);
+        let ctx = DeidContext {
+            uids: &uids,
+            cleaner: PatientCleaner::new(&cleaner, &uids, data),
+            requirements: Requirements::new(data),
+            private: &private,
+            trail: FileTrail::default(),
+            pseudonym: None,
+            template_values: Default::default(),
+        };
+        de_identify_dataset(data, profile, None, &ctx).unwrap();
+    }
+
+    fn nested(data: &InMemDicomObject) -> &InMemDicomObject {
+        let outer = &data.get(tags::SHARED_FUNCTIONAL_GROUPS_SEQUENCE).unwrap().items().unwrap()[0];
+        &outer.get(tags::DERIVATION_CODE_SEQUENCE).unwrap().items().unwrap()[0]
+    }
+
+    fn nested_data() -> InMemDicomObject {
+        let inner = sequence(tags::DERIVATION_CODE_SEQUENCE, vec![item(patient())]);
+        let outer = sequence(tags::SHARED_FUNCTIONAL_GROUPS_SEQUENCE, vec![item(vec![inner])]);
+        item(patient().into_iter().chain([outer]).collect())
+    }
+
+    #[test]
+    fn de_identify_nested_items() {
+        let mut data = nested_data();
+        de_identify(&mut data, &Profile::default());
+
+        for level in [&data, nested(&data)] {
+            // Z: zero-length, X: removed
+            assert_eq!(level.get(tags::PATIENT_NAME).unwrap().to_str().unwrap(), "");
+            assert!(level.get(tags::PATIENT_ADDRESS).is_none());
+        }
     }
-    Ok(data)
 
+    #[test]
+    fn remove_nested_patient_name() {
+        let mut data = nested_data();
+        let profile = Profile { action: Some(ActionOverride::Remove), ..Default::default() };
+        de_identify(&mut data, &profile);
 
-    // for element in data.into_iter() {
-    //     let tag = element.header().tag;
-    //     // TODO: Get both u16 and change the DEID HASH from Tag to (u16, u16)
-    //     let vr = element.header().vr;
-    //     // Check if contains
-    //     // if yes: apply the 1st profile
-    //     // + check for the rest of profiles to add afterwards 
-    //     // else : pass
-    // }
-    // }
-    // Ok(data)
+        assert!(data.get(tags::PATIENT_NAME).is_none());
+        assert!(nested(&data).get(tags::PATIENT_NAME).is_none());
+    }
 
+    fn write_input(path: &Path, patient_id: &str, sop_instance_uid: &str) {
+        let obj = item(vec![
+            DataElement::new(tags::SOP_CLASS_UID, VR::UI, PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.7")),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, PrimitiveValue::from(sop_instance_uid)),
+            DataElement::new(tags::PATIENT_ID, VR::LO, PrimitiveValue::from(patient_id)),
+        ]);
+        let meta = FileMetaTableBuilder::new()
+            .transfer_syntax("1.2.840.10008.1.2.1")
+            .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.7")
+            .media_storage_sop_instance_uid(sop_instance_uid);
+        obj.with_meta(meta).unwrap().write_to_file(path).unwrap();
+    }
 
+    fn config(dir: &Path, resume: bool) -> AnoConfig {
+        AnoConfig {
+            input_path: dir.join("in"),
+            output_path: dir.join("out"),
+            dry: false,
+            with_pixel_data: false,
+            jobs: 1,
+            verbose: false,
+            uid_secret: Some("secret".to_string()),
+            policy: Policy::default(),
+            profile: Profile::default(),
+            action: None,
+            clean_patterns: None,
+            private_mode: None,
+            redact_rules: None,
+            audit_path: Some(dir.join("audit.csv")),
+            mapping_path: Some(dir.join("mapping.csv")),
+            pseudonyms: Some(PseudonymSource::Hash),
+            verify: false,
+            verify_report: None,
+            custom_profile: None,
+            name_template: None,
+            backup: false,
+            journal_path: None,
+            resume,
+            confirmation: Confirmation::Yes,
+        }
+    }
 
+    #[test]
+    fn resume_keeps_the_audit_and_the_mapping() {
+        let dir = std::env::temp_dir().join(format!("pulsedcm-ano-resume-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("in")).unwrap();
+        let files = vec![dir.join("in/1.dcm"), dir.join("in/2.dcm")];
+        write_input(&files[0], "AB12", "1.2.3.1");
+        write_input(&files[1], "CD34", "1.2.3.2");
+
+        // Interrupted after the first file
+        threading_handling(files[..1].to_vec(), config(&dir, false), &RunSummary::new()).unwrap();
+        threading_handling(files.clone(), config(&dir, true), &RunSummary::new()).unwrap();
+
+        let mapping = std::fs::read_to_string(dir.join("mapping.csv")).unwrap();
+        for original in ["AB12", "CD34", "1.2.3.1", "1.2.3.2"] {
+            assert!(mapping.contains(&format!(",{},", original)), "{} missing from\n{}", original, mapping);
+        }
+        let audit = std::fs::read_to_string(dir.join("audit.csv")).unwrap();
+        assert_eq!(audit.matches("input,output,tag").count(), 1);
+        for file in &files {
+            assert!(audit.contains(&*file.to_string_lossy()), "{} missing from the audit", file.display());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,160 @@
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, DataElement, DataSetSequence, FileDicomObject, InMemDicomObject,
+    PrimitiveValue, VR,
+};
+
+use crate::private::PrivateMode;
+use crate::profile::Profile;
+
+/// De-identification methods of CID 7050, as (code value, code meaning)
+const BASIC_PROFILE: (&str, &str) = ("113100", "Basic Application Confidentiality Profile");
+const CLEAN_PIXEL_DATA: (&str, &str) = ("113101", "Clean Pixel Data Option");
+const CLEAN_GRAPHICS: (&str, &str) = ("113103", "Clean Graphics Option");
+const CLEAN_STRUCTURED_CONTENT: (&str, &str) = ("113104", "Clean Structured Content Option");
+const CLEAN_DESCRIPTORS: (&str, &str) = ("113105", "Clean Descriptors Option");
+const RETAIN_FULL_DATES: (&str, &str) = ("113106", "Retain Longitudinal Temporal Information Full Dates Option");
+const RETAIN_MODIFIED_DATES: (&str, &str) = ("113107", "Retain Longitudinal Temporal Information Modified Dates Option");
+const RETAIN_PATIENT_CHARS: (&str, &str) = ("113108", "Retain Patient Characteristics Option");
+const RETAIN_DEVICE_ID: (&str, &str) = ("113109", "Retain Device Identity Option");
+const RETAIN_UIDS: (&str, &str) = ("113110", "Retain UIDs Option");
+const RETAIN_SAFE_PRIVATE: (&str, &str) = ("113111", "Retain Safe Private Option");
+const RETAIN_INSTITUTION_ID: (&str, &str) = ("113112", "Retain Institution Identity Option");
+
+/// Described in De-identification Method only, no option of CID 7050 keeps them
+const PRIVATE_UNFILTERED: &str = "Private attributes retained unfiltered";
+
+/// Describe how the file was de-identified (PS3.15 E.1.1), and bring the file meta group
+/// in line with the modified dataset
+pub fn mark_deidentified(
+    data: &mut FileDicomObject<InMemDicomObject>,
+    profile: &Profile,
+    private_mode: PrivateMode,
+    pixels_cleaned: bool,
+    custom: bool,
+) {
+    let methods = methods(profile, private_mode, pixels_cleaned);
+
+    let mut descriptions: Vec<String> = methods.iter().map(|(_, meaning)| meaning.to_string()).collect();
+    if private_mode == PrivateMode::Keep {
+        descriptions.push(PRIVATE_UNFILTERED.to_string());
+    }
+    if custom {
+        descriptions.push("pulsedcm custom profile".to_string());
+    }
+    let items: Vec<InMemDicomObject> = methods
+        .iter()
+        .map(|(value, meaning)| {
+            InMemDicomObject::from_element_iter([
+                DataElement::new(tags::CODE_VALUE, VR::SH, PrimitiveValue::from(*value)),
+                DataElement::new(tags::CODING_SCHEME_DESIGNATOR, VR::SH, PrimitiveValue::from("DCM")),
+                DataElement::new(tags::CODE_MEANING, VR::LO, PrimitiveValue::from(*meaning)),
+            ])
+        })
+        .collect();
+
+    data.put_str(tags::PATIENT_IDENTITY_REMOVED, VR::CS, "YES");
+    data.put(DataElement::new(
+        tags::DEIDENTIFICATION_METHOD,
+        VR::LO,
+        PrimitiveValue::Strs(descriptions.into()),
+    ));
+    data.put(DataElement::new(
+        tags::DEIDENTIFICATION_METHOD_CODE_SEQUENCE,
+        VR::SQ,
+        DataSetSequence::from(items),
+    ));
+    // Dates are kept, shifted by the clean action or removed
+    let temporal = if profile.ret_lg_full_dt {
+        "UNMODIFIED"
+    } else if profile.ret_lg_mod_dt {
+        "MODIFIED"
+    } else {
+        "REMOVED"
+    };
+    data.put_str(tags::LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED, VR::CS, temporal);
+
+    sync_meta(data);
+}
+
+/// Profile and options applied, as CID 7050 codes
+fn methods(profile: &Profile, private_mode: PrivateMode, pixels_cleaned: bool) -> Vec<(&'static str, &'static str)> {
+    let mut methods = vec![BASIC_PROFILE];
+    let options = [
+        (pixels_cleaned, CLEAN_PIXEL_DATA),
+        (profile.cln_graph, CLEAN_GRAPHICS),
+        (profile.cln_struc_cnt, CLEAN_STRUCTURED_CONTENT),
+        (profile.cln_desc, CLEAN_DESCRIPTORS),
+        (profile.ret_lg_full_dt, RETAIN_FULL_DATES),
+        (profile.ret_lg_mod_dt, RETAIN_MODIFIED_DATES),
+        (profile.ret_pt_char, RETAIN_PATIENT_CHARS),
+        (profile.ret_dev_id, RETAIN_DEVICE_ID),
+        (profile.ret_uids, RETAIN_UIDS),
+        (private_mode == PrivateMode::KeepSafe, RETAIN_SAFE_PRIVATE),
+        (profile.ret_inst_id, RETAIN_INSTITUTION_ID),
+    ];
+    methods.extend(options.iter().filter(|(enabled, _)| *enabled).map(|(_, method)| *method));
+    methods
+}
+
+/// The SOP Instance UID may have been remapped, the SOP Class UID never is
+fn sync_meta(data: &mut FileDicomObject<InMemDicomObject>) {
+    let uid = |tag| {
+        data.get(tag)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim_end_matches(['\0', ' ']).to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let instance = uid(tags::SOP_INSTANCE_UID);
+    let class = uid(tags::SOP_CLASS_UID);
+
+    let meta = data.meta_mut();
+    if let Some(instance) = instance {
+        meta.media_storage_sop_instance_uid = instance;
+    }
+    if let Some(class) = class {
+        meta.media_storage_sop_class_uid = class;
+    }
+    meta.update_information_group_length();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::FileMetaTableBuilder;
+
+    #[test]
+    fn only_safe_private_attributes_are_retained_safe_private() {
+        let profile = Profile::default();
+        assert_eq!(methods(&profile, PrivateMode::Remove, false), [BASIC_PROFILE]);
+        assert_eq!(methods(&profile, PrivateMode::KeepSafe, false), [BASIC_PROFILE, RETAIN_SAFE_PRIVATE]);
+        assert_eq!(methods(&profile, PrivateMode::Keep, false), [BASIC_PROFILE]);
+    }
+
+    #[test]
+    fn describe_unfiltered_private_attributes() {
+        let descriptions = |mode| {
+            let mut data = InMemDicomObject::new_empty()
+                .with_meta(
+                    FileMetaTableBuilder::new()
+                        .transfer_syntax("1.2.840.10008.1.2.1")
+                        .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.7")
+                        .media_storage_sop_instance_uid("1.2.3"),
+                )
+                .unwrap();
+            mark_deidentified(&mut data, &Profile::default(), mode, false, false);
+            data.get(tags::DEIDENTIFICATION_METHOD).unwrap().to_multi_str().unwrap().to_vec()
+        };
+        assert_eq!(descriptions(PrivateMode::Keep), [BASIC_PROFILE.1, PRIVATE_UNFILTERED]);
+        assert_eq!(descriptions(PrivateMode::KeepSafe), [BASIC_PROFILE.1, RETAIN_SAFE_PRIVATE.1]);
+        assert_eq!(descriptions(PrivateMode::Remove), [BASIC_PROFILE.1]);
+    }
+
+    #[test]
+    fn options_follow_the_profile() {
+        let profile = Profile { ret_uids: true, ret_lg_mod_dt: true, ..Default::default() };
+        assert_eq!(
+            methods(&profile, PrivateMode::Remove, true),
+            [BASIC_PROFILE, CLEAN_PIXEL_DATA, RETAIN_MODIFIED_DATES, RETAIN_UIDS]
+        );
+    }
+}
@@ -1,7 +1,18 @@
 use pulsedcm_core::{FileDicomObject, InMemDicomObject, PrimitiveValue, Value, Tag, VR, Result};
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use phf::*;
 use smallvec::smallvec;
 
+use crate::audit::FileTrail;
+use crate::clean::PatientCleaner;
+use crate::conformance::{Requirement, Requirements};
+use crate::private::PrivateTags;
+use crate::pseudonym::Pseudonym;
+use crate::uid::UidGenerator;
+
+#[derive(Debug)]
 pub enum ActionCode {
     D,       // Replace with dummy value
     Z,       // Zero-length or dummy
@@ -16,6 +27,31 @@ pub enum ActionCode {
     XZU,     // X unless Z/U required for conformance
 }
 
+impl FromStr for ActionCode {
+    type Err = String;
+
+    /// Supplement 142 notation, the slashes of compound actions being optional
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_uppercase().replace('/', "").as_str() {
+            "D" => Ok(Self::D),
+            "Z" => Ok(Self::Z),
+            "X" => Ok(Self::X),
+            "K" => Ok(Self::K),
+            "C" => Ok(Self::C),
+            "U" => Ok(Self::U),
+            "ZD" => Ok(Self::ZD),
+            "XZ" => Ok(Self::XZ),
+            "XD" => Ok(Self::XD),
+            "XZD" => Ok(Self::XZD),
+            "XZU" => Ok(Self::XZU),
+            _ => Err(format!(
+                "Unknown action '{}', expected one of: D, Z, X, K, C, U, Z/D, X/Z, X/D, X/Z/D, X/Z/U",
+                s
+            )),
+        }
+    }
+}
+
 fn dummy_from_vr(vr: &VR) -> PrimitiveValue{
     match vr {
         // String-like
@@ -63,13 +99,31 @@ fn dummy_from_vr(vr: &VR) -> PrimitiveValue{
     }
 }
 
+/// Per-file state needed by the actions
+pub struct DeidContext<'a> {
+    pub uids: &'a UidGenerator,
+    pub cleaner: PatientCleaner<'a>,
+    pub requirements: Requirements,
+    pub private: &'a PrivateTags,
+    pub trail: FileTrail,
+    pub pseudonym: Option<Pseudonym>,
+    pub template_values: HashMap<Tag, String>,
+}
+
 impl ActionCode {
-    pub fn process(&self, data: &mut InMemDicomObject, tag: &Tag, vr: &VR) -> Result<()>  {
+    pub fn process(&self, data: &mut InMemDicomObject, tag: &Tag, vr: &VR, ctx: &DeidContext) -> Result<()>  {
+        // Compound actions are recorded as the simple action they resolve to
+        if let Some(code) = self.as_str() {
+            ctx.trail.record(ctx.uids, data.get(*tag), *tag, code);
+        }
         match self {
             Self::D => {
                 // Replace with dummy value consistent with the VR
+                // Sequences keep their items, de-identified when walking the dataset
                 data.update_value_at(*tag, |v|{
-                    *v.primitive_mut().unwrap() = dummy_from_vr(vr);
+                    if let Some(val) = v.primitive_mut() {
+                        *val = dummy_from_vr(vr);
+                    }
                 })?;
                 Ok(())
             }, 
@@ -78,7 +132,12 @@ impl ActionCode {
                 // Replace with zero length value or dummy
                 // TODO: Handle if Empty is processed, otherwise use the dummy_from_vr
                 data.update_value_at(*tag, |v|{
-                    *v.primitive_mut().unwrap() = PrimitiveValue::Empty;
+                    if let Some(items) = v.items_mut() {
+                        // Zero length sequence
+                        items.clear();
+                    } else if let Some(val) = v.primitive_mut() {
+                        *val = PrimitiveValue::Empty;
+                    }
                 })?;
                 Ok(())
             },
@@ -90,34 +149,79 @@ impl ActionCode {
                 Ok(())
 
             },
-            // Self::K => {
-            //     // Keep non SQ and de-identify SQ
-            //     data.update_value_at(*tag, |&mut v| -> Result<()>{
-            //         if *vr == VR::SQ{
-            //             if let Value::Sequence(ref mut items) = v {
-            //                 for i in items.items_mut() {
-            //                     Self::process(&self, i, tag, vr)?;
-            //                 }
-            //             }
-            //         }
-            //     })?;
-            //     Ok(())
-            // },
-            _ => {
+            Self::K => {
+                // Keep non SQ, SQ items are de-identified when walking the dataset
+                Ok(())
+            },
+            Self::U => {
+                // Replace with a UID derived from the original one, same input => same output
                 data.update_value_at(*tag, |v|{
-                    if let Some(val) =  v.primitive_mut() {
-                        *val = dummy_from_vr(vr);
+                    if let Some(val) = v.primitive_mut() {
+                        let remapped: Vec<String> = val
+                            .to_multi_str()
+                            .iter()
+                            .map(|uid| {
+                                let new = ctx.uids.remap(uid);
+                                ctx.trail.map("UID", uid.trim_end_matches(['\0', ' ']), &new);
+                                new
+                            })
+                            .collect();
+                        if !remapped.is_empty() {
+                            *val = PrimitiveValue::Strs(remapped.into());
+                        }
                     };
                 })?;
                 Ok(())
-            }
-            // Self::C => {},
-            // Self::U => {},
-            // Self::ZD => {},
-            // Self::XZ => {},
-            // Self::XD => {},
-            // Self::XZD => {},
-            // Self::XZU => {},
+            },
+            Self::C => {
+                // Clean: shift dates, scrub descriptors, dummy for anything else
+                // Sequences are kept, their items being cleaned when walking the dataset
+                data.update_value_at(*tag, |v|{
+                    if let Some(val) = v.primitive_mut() {
+                        *val = ctx.cleaner.clean(val, vr).unwrap_or_else(|| dummy_from_vr(vr));
+                    };
+                })?;
+                Ok(())
+            },
+            Self::ZD | Self::XZ | Self::XD | Self::XZD | Self::XZU => {
+                // Least destructive action keeping the attribute type of the file's IOD
+                self.conformant(ctx.requirements.of(*tag)).process(data, tag, vr, ctx)
+            },
+        }
+    }
+
+    /// Code of a simple action, `None` for compound ones
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::D => Some("D"),
+            Self::Z => Some("Z"),
+            Self::X => Some("X"),
+            Self::K => Some("K"),
+            Self::C => Some("C"),
+            Self::U => Some("U"),
+            Self::ZD | Self::XZ | Self::XD | Self::XZD | Self::XZU => None,
+        }
+    }
+
+    /// Simple action a compound action stands for given the attribute requirement type
+    fn conformant(&self, requirement: Requirement) -> &'static ActionCode {
+        match (self, requirement) {
+            (Self::ZD, Requirement::Type1) => &Self::D,
+            (Self::ZD, _) => &Self::Z,
+            (Self::XD, Requirement::Type3) => &Self::X,
+            (Self::XD, _) => &Self::D,
+            (Self::XZ | Self::XZD | Self::XZU, Requirement::Type3) => &Self::X,
+            (Self::XZ | Self::XZD | Self::XZU, Requirement::Type2) => &Self::Z,
+            // Type 1 sequences keep their items, the referenced UIDs are remapped when walking them
+            (Self::XZU, Requirement::Type1) => &Self::U,
+            // XZ has no Type 1 case in the standard, a dummy is the only conformant value
+            (Self::XZ | Self::XZD, Requirement::Type1) => &Self::D,
+            (Self::D, _) => &Self::D,
+            (Self::Z, _) => &Self::Z,
+            (Self::X, _) => &Self::X,
+            (Self::K, _) => &Self::K,
+            (Self::C, _) => &Self::C,
+            (Self::U, _) => &Self::U,
         }
     }
 }
@@ -137,649 +241,650 @@ pub struct PolicyAction{
 }
 
 const ALL_TAGS: usize = 642; // Should be 649 if taking every single parts of chapter E
+// Keys must be suffixed: phf_map hashes unsuffixed literals as i32, which never match (u16, u16) lookups
 pub static DEID_HASH: phf::Map<(u16, u16), PolicyAction> = phf_map!{
-    (0x0000,0x1000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0000,0x1001) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0002,0x0003) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0004,0x1511) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0012) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0013) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0014) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0015) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0017) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0018) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0019) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0020) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0021) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0022) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0023) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0024) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0025) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x002A) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0030) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0031) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0032) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0033) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0034) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0035) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0050) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0054) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0055) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0058) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0080) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0081) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0082) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0090) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0092) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0094) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0096) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x009C) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x009D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0106) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0107) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x0201) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1010) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1030) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x103E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1041) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1048) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1049) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1052) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1060) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1062) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1070) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1072) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1080) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1084) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1088) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1110) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1111) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1120) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1140) => PolicyAction{basic:ActionCode::XZU,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1155) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1195) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1301) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1302) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1303) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x1304) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x2111) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x2112) => PolicyAction{basic:ActionCode::XZU,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x3010) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0008,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0010) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0012) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0013) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0014) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0015) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0016) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0020) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0021) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0030) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0032) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0040) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0041) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0042) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0043) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0044) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0045) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0046) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0047) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0101) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x0102) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1002) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1020) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1030) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1060) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1080) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1081) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1090) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x1100) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2110) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2150) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2152) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2154) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2155) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2160) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2161) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2162) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2180) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x21A0) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x21B0) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x21C0) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x21D0) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x21F0) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2203) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2297) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x2299) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0010,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0010) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0020) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0021) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0022) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0023) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0030) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0031) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0032) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0040) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0041) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0042) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0043) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0050) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0051) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0055) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0060) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0071) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0072) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0073) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0081) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0082) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0086) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0012,0x0087) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0014,0x407C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0014,0x407E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x002B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x004B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x004D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x004E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x004F) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0051) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0070) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0071) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0072) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0073) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0074) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0075) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0076) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0077) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0078) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0079) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x007F) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0080) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0081) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0082) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0083) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0084) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0085) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0086) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0087) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0088) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x0089) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x008A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x008B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x008C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x008D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0016,0x008E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x0010) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x0027) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x0035) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1000) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1002) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1007) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1008) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1009) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x100A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x100B) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1012) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1014) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1030) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1042) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1043) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1072) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1073) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1078) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1079) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x11BB) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1200) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1201) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1202) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1203) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1204) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1205) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x1400) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x2042) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x5011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x700A) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x700C) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x700E) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9074) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9151) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9185) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9367) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9369) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x936A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9371) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9373) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x937B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x937F) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9424) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9516) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9517) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9623) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9701) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9804) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9919) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0x9937) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0xA002) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0018,0xA003) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x000D) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x000E) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x0010) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x0027) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x0052) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x0200) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x3401) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x3403) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x3405) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x3406) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x9158) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x9161) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0020,0x9164) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0028,0x1199) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0028,0x1214) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0028,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x0012) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x0032) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x0033) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x0034) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x0035) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1020) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1021) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1030) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1032) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1033) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1041) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1051) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1060) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1066) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1067) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x1070) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0032,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0034,0x0001) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0034,0x0002) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0034,0x0005) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0034,0x0007) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0014) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x001A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x001B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x001C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x001D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x001E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0020) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0021) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0030) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0032) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0060) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0061) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0062) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0064) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0300) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0400) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x0500) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0038,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x003A,0x0310) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x003A,0x0314) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x003A,0x0329) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x003A,0x032B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0002) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0003) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0006) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0007) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0009) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x000B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0012) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0241) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0242) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0243) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0244) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0245) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0250) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0251) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0253) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0254) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0275) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0280) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0310) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x050A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0512) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0513) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x051A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0551) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0554) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0555) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
-    (0x0040,0x0562) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0600) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0602) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x0610) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
-    (0x0040,0x06FA) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1002) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x100A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1101) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1102) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1103) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1104) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x1400) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2008) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2009) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2016) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2017) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x2400) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x3001) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4005) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4008) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4011) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4023) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4025) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4027) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4028) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4030) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4034) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4035) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4036) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4037) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4051) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0x4052) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA023) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA024) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA027) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA030) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA032) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA033) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA034) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA035) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA073) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA075) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA078) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA07A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA07C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA082) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA088) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA110) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA112) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA120) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA121) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA122) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA123) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA124) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA13A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA171) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA172) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA192) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA193) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA307) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA352) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA353) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA354) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA358) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA402) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xA730) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
-    (0x0040,0xB034) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xB036) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xB03B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xB03F) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xDB06) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xDB07) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xDB0C) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xDB0D) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0040,0xE004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0042,0x0011) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0044,0x0004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0044,0x000B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0044,0x0010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0044,0x0104) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0044,0x0105) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0050,0x001B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0050,0x0020) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0050,0x0021) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0062,0x0021) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0064,0x0003) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0068,0x6226) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0068,0x6270) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x006A,0x0003) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x006A,0x0005) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x006A,0x0006) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x0001) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:Some(ActionCode::C)},
-    (0x0070,0x0082) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x0083) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x0084) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x0086) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x031A) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x1101) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0070,0x1102) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x000A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x005E) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x005F) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0061) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0063) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0065) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0066) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0068) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x006A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x006B) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x006C) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x006D) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x006E) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0070) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0072,0x0071) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0074,0x1234) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0074,0x1236) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0140) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0200) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0904) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0906) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0910) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0088,0x0912) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0100,0x0420) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0100) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0105) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0115) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0310) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0402) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0403) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0404) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0550) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0551) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0552) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0561) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0562) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0563) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0564) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0565) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x0400,0x0600) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2030,0x0020) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2100,0x0040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2100,0x0050) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2100,0x0070) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2100,0x0140) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x2200,0x0002) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x2200,0x0005) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3002,0x0121) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3002,0x0123) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0002) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0006) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0008) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0009) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0024) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0026) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0028) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x002D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x002E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0038) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x004D) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x004E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0085) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x0088) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x00A6) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3006,0x00C2) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0024) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0025) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0054) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0056) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0105) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0162) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0164) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0166) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0168) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0250) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3008,0x0251) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0002) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0003) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0004) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0006) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0007) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x000B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x000E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0013) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0016) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0054) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0072) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0083) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x00B2) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x00C3) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x00DD) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0196) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x01A6) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x01B2) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0216) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x022C) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x022E) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x02EB) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0608) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0609) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0611) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0615) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0619) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0623) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x062A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0650) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0676) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x067C) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x067D) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0700) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0734) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0736) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x073A) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0741) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0742) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0760) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0783) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0785) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x078E) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0792) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x0794) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300A,0x079A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300C,0x0113) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x300C,0x0127) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300E,0x0004) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300E,0x0005) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x300E,0x0008) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0006) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x000B) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x000F) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0013) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0015) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0017) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x001B) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x002D) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0031) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0033) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0034) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0035) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0036) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0037) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0038) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x003B) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0043) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x004C) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x004D) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0054) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0056) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x005A) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x005C) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0061) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x006E) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x006F) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0077) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x007A) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x007B) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x007F) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0081) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x3010,0x0085) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4000,0x0010) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4000,0x4000) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0040) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0042) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0100) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0101) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0102) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0108) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0109) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x010A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x010B) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x010C) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0111) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0112) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0113) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0114) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0115) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0118) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0119) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x011A) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0200) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0202) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
-    (0x4008,0x0300) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0000u16,0x1000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0000u16,0x1001u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0002u16,0x0003u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0004u16,0x1511u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0012u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0013u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0014u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0015u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0017u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0018u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0019u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0020u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0021u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0022u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0023u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0024u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0025u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x002Au16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0030u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0031u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0032u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0033u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0034u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0035u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0050u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0054u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0055u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0058u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0080u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0081u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0082u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0090u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0092u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0094u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0096u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x009Cu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x009Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0106u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0107u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x0201u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1010u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1030u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x103Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1041u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1048u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1049u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1052u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1060u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1062u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1070u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1072u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1080u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1084u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1088u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1110u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1111u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1120u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1140u16) => PolicyAction{basic:ActionCode::XZU,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1155u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1195u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1301u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1302u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1303u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x1304u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x2111u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x2112u16) => PolicyAction{basic:ActionCode::XZU,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x3010u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0008u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0010u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0012u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0013u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0014u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0015u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0016u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0020u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0021u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0030u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0032u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0040u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0041u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0042u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0043u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0044u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0045u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0046u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0047u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0101u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x0102u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1002u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1020u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1030u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1060u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1080u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1081u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1090u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x1100u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2110u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2150u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2152u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2154u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2155u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2160u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2161u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2162u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2180u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x21A0u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x21B0u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x21C0u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x21D0u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x21F0u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2203u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2297u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x2299u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0010u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0010u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0020u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0021u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0022u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0023u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0030u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0031u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0032u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0040u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0041u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0042u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0043u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0050u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0051u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0055u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0060u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0071u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0072u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0073u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0081u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0082u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0086u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0012u16,0x0087u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0014u16,0x407Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0014u16,0x407Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x002Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x004Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x004Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x004Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x004Fu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0051u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0070u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0071u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0072u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0073u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0074u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0075u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0076u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0077u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0078u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0079u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x007Fu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0080u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0081u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0082u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0083u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0084u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0085u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0086u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0087u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0088u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x0089u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x008Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x008Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x008Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x008Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0016u16,0x008Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x0010u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x0027u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x0035u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1000u16) => PolicyAction{basic:ActionCode::XZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1002u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1007u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1008u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1009u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x100Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x100Bu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1012u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1014u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1030u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1042u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1043u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1072u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1073u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1078u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1079u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x11BBu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1200u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1201u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1202u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1203u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1204u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1205u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x1400u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x2042u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x5011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x700Au16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x700Cu16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x700Eu16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9074u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9151u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9185u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9367u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9369u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x936Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9371u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9373u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x937Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x937Fu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9424u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9516u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9517u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9623u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9701u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9804u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9919u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0x9937u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0xA002u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0018u16,0xA003u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x000Du16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x000Eu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x0010u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x0027u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x0052u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x0200u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x3401u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x3403u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x3405u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x3406u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x9158u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x9161u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0020u16,0x9164u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0028u16,0x1199u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0028u16,0x1214u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0028u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x0012u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x0032u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x0033u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x0034u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x0035u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1020u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1021u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1030u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1032u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1033u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1041u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1051u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1060u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1066u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1067u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x1070u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0032u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0034u16,0x0001u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0034u16,0x0002u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0034u16,0x0005u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0034u16,0x0007u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0014u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x001Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x001Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x001Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x001Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x001Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0020u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0021u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0030u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0032u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0060u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0061u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0062u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0064u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0300u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0400u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x0500u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0038u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x003Au16,0x0310u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x003Au16,0x0314u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x003Au16,0x0329u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x003Au16,0x032Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0002u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0003u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0006u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0007u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0009u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x000Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0012u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::C),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0241u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0242u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0243u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0244u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0245u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0250u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0251u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0253u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0254u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0275u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0280u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0310u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x050Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0512u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0513u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x051Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0551u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0554u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0555u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
+    (0x0040u16,0x0562u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0600u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0602u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x0610u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
+    (0x0040u16,0x06FAu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1002u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x100Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1101u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1102u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1103u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1104u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x1400u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2008u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2009u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2016u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2017u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x2400u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x3001u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4005u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4008u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4011u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4023u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4025u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4027u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4028u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4030u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4034u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4035u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4036u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4037u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4051u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0x4052u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA023u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA024u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA027u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA030u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA032u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA033u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA034u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA035u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA073u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA075u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA078u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA07Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA07Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA082u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA088u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA110u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA112u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA120u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA121u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA122u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA123u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA124u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA13Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA171u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA172u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA192u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA193u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA307u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA352u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA353u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA354u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA358u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA402u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xA730u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:Some(ActionCode::C),cln_graph:None},
+    (0x0040u16,0xB034u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xB036u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xB03Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xB03Fu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xDB06u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xDB07u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xDB0Cu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xDB0Du16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0040u16,0xE004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0042u16,0x0011u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0044u16,0x0004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0044u16,0x000Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0044u16,0x0010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0044u16,0x0104u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0044u16,0x0105u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0050u16,0x001Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0050u16,0x0020u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0050u16,0x0021u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0062u16,0x0021u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0064u16,0x0003u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0068u16,0x6226u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0068u16,0x6270u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x006Au16,0x0003u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x006Au16,0x0005u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x006Au16,0x0006u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x0001u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:Some(ActionCode::C)},
+    (0x0070u16,0x0082u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x0083u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x0084u16) => PolicyAction{basic:ActionCode::ZD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x0086u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x031Au16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x1101u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0070u16,0x1102u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x000Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x005Eu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x005Fu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:Some(ActionCode::K),ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0061u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0063u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0065u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0066u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0068u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x006Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x006Bu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x006Cu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x006Du16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x006Eu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0070u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0072u16,0x0071u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0074u16,0x1234u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0074u16,0x1236u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0140u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0200u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0904u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0906u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0910u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0088u16,0x0912u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0100u16,0x0420u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0100u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0105u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0115u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0310u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0402u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0403u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0404u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0550u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0551u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0552u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0561u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0562u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0563u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0564u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:Some(ActionCode::K),ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0565u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x0400u16,0x0600u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2030u16,0x0020u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2100u16,0x0040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2100u16,0x0050u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2100u16,0x0070u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2100u16,0x0140u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::C),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x2200u16,0x0002u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x2200u16,0x0005u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3002u16,0x0121u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3002u16,0x0123u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0002u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0006u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0008u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0009u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0024u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0026u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0028u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x002Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x002Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0038u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x004Du16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x004Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0085u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x0088u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x00A6u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3006u16,0x00C2u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0024u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0025u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0054u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0056u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0105u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0162u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0164u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0166u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0168u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0250u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3008u16,0x0251u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0002u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0003u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0004u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0006u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0007u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x000Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x000Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0013u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0016u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0054u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0072u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0083u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x00B2u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x00C3u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x00DDu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0196u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x01A6u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x01B2u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0216u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x022Cu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x022Eu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x02EBu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0608u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0609u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0611u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0615u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0619u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0623u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x062Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0650u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0676u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x067Cu16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x067Du16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0700u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0734u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0736u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x073Au16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0741u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0742u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0760u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0783u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0785u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x078Eu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0792u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x0794u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Au16,0x079Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Cu16,0x0113u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x300Cu16,0x0127u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Eu16,0x0004u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Eu16,0x0005u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x300Eu16,0x0008u16) => PolicyAction{basic:ActionCode::XZ,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0006u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x000Bu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x000Fu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0013u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0015u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0017u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x001Bu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x002Du16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0031u16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0033u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0034u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0035u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0036u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0037u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0038u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x003Bu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0043u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:Some(ActionCode::K),ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x004Cu16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x004Du16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0054u16) => PolicyAction{basic:ActionCode::D,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0056u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x005Au16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x005Cu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0061u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x006Eu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x006Fu16) => PolicyAction{basic:ActionCode::U,ret_sf_priv:None,ret_uids:Some(ActionCode::K),ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0077u16) => PolicyAction{basic:ActionCode::XD,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x007Au16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x007Bu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x007Fu16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0081u16) => PolicyAction{basic:ActionCode::Z,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x3010u16,0x0085u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4000u16,0x0010u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4000u16,0x4000u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0040u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0042u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0100u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0101u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0102u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0108u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0109u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x010Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x010Bu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x010Cu16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0111u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0112u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0113u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:Some(ActionCode::K),ret_lg_mod_dt:Some(ActionCode::C),cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0114u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0115u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0118u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0119u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x011Au16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0200u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0202u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:None,cln_struc_cnt:None,cln_graph:None},
+    (0x4008u16,0x0300u16) => PolicyAction{basic:ActionCode::X,ret_sf_priv:None,ret_uids:None,ret_dev_id:None,ret_inst_id:None,ret_pt_char:None,ret_lg_full_dt:None,ret_lg_mod_dt:None,cln_desc:Some(ActionCode::C),cln_struc_cnt:None,cln_graph:None},
 };
 
 
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::fs::{copy, create_dir_all, hard_link, rename, remove_file, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use pulsedcm_core::{
+    Confirmation, DataDictionary, FileDicomObject, InMemDicomObject, mirror_path, PulseError, PulseErrorKind, Result, StandardDataDictionary,
+    Tag,
+};
+
+/// Relative output path made of `{Keyword}` placeholders filled with the de-identified
+/// attributes, e.g. `{PatientID}/{StudyInstanceUID}/{SeriesNumber}/{InstanceNumber}.dcm`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathTemplate(Vec<Part>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Attribute(Tag),
+}
+
+impl FromStr for PathTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let path = Path::new(s);
+        if s.trim().is_empty()
+            || path.components().any(|c| !matches!(c, Component::Normal(_)))
+        {
+            return Err(format!("Invalid template '{}', expected a relative path without '..'", s));
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .map(|e| start + e)
+                .ok_or_else(|| format!("Unclosed '{{' in template '{}'", s))?;
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_string()));
+            }
+            let keyword = rest[start + 1..end].trim();
+            let tag = StandardDataDictionary
+                .by_name(keyword)
+                .map(|e| e.tag.inner())
+                .ok_or_else(|| format!("Unknown attribute '{}' in template '{}'", keyword, s))?;
+            parts.push(Part::Attribute(tag));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        Ok(Self(parts))
+    }
+}
+
+impl PathTemplate {
+    fn render(&self, data: &InMemDicomObject) -> PathBuf {
+        let path: String = self
+            .0
+            .iter()
+            .map(|p| match p {
+                Part::Text(text) => text.clone(),
+                Part::Attribute(tag) => {
+                    let value = data
+                        .get(*tag)
+                        .and_then(|e| e.to_str().ok())
+                        .map(|s| s.trim_end_matches(['\0', ' ']).trim().to_string())
+                        .unwrap_or_default();
+                    sanitize(&value)
+                }
+            })
+            .collect();
+        PathBuf::from(path)
+    }
+}
+
+/// Attribute values can't add path components
+fn sanitize(value: &str) -> String {
+    let value: String = value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || "._-^".contains(c) { c } else { '_' })
+        .collect();
+    match value.as_str() {
+        "" => "unknown".to_string(),
+        "." | ".." => value.replace('.', "_"),
+        _ => value,
+    }
+}
+
+/// Where the files of a run are written: the input tree mirrored under the output
+/// directory, or the path template rendered under it
+#[derive(Debug)]
+pub struct OutputLayout {
+    input_root: PathBuf,
+    output_root: PathBuf,
+    template: Option<PathTemplate>,
+    confirmation: Confirmation,
+    backup: bool, // Keep the overwritten files as `.orig`
+    // Destination already used, with its input, so that nothing gets overwritten silently
+    taken: Mutex<HashMap<PathBuf, PathBuf>>,
+}
+
+impl OutputLayout {
+    pub fn new(
+        input_root: PathBuf,
+        output_root: PathBuf,
+        template: Option<PathTemplate>,
+        confirmation: Confirmation,
+        backup: bool,
+    ) -> Self {
+        Self {
+            input_root,
+            output_root,
+            template,
+            confirmation,
+            backup,
+            taken: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Every input file is overwritten by its de-identified version
+    pub fn in_place(&self) -> bool {
+        self.template.is_none() && self.output_root == self.input_root
+    }
+
+    /// Destination of a de-identified file, its directory being created if needed.
+    /// Overwriting the input files is confirmed once for the run, see `in_place`
+    pub fn destination(&self, input: &Path, data: &InMemDicomObject) -> Result<PathBuf> {
+        let destination = match &self.template {
+            Some(template) => self.output_root.join(template.render(data)),
+            None => mirror_path(&self.input_root, &self.output_root, input)?,
+        };
+
+        if self.output_root.is_file() && destination != self.output_root {
+            return Err(layout_error("Output path shouldn't be a file".to_string()));
+        }
+        if !self.in_place() {
+            self.confirmation.check_destination(&destination)?;
+        }
+        if let Some(previous) = self.taken.lock()?.insert(destination.clone(), input.to_path_buf()) {
+            return Err(layout_error(format!(
+                "{} and {} would both be written to {}",
+                previous.display(),
+                input.display(),
+                destination.display()
+            )));
+        }
+        if let Some(parent) = destination.parent() {
+            create_dir_all(parent)?;
+        }
+        Ok(destination)
+    }
+
+    /// Write through a temporary file next to the destination, synced then renamed over it:
+    /// a failed write leaves no truncated file and the original untouched
+    pub fn write(&self, data: &FileDicomObject<InMemDicomObject>, destination: &Path) -> Result<()> {
+        // Serialized first, the writer of dicom-rs dropping the errors of its last flush
+        let mut bytes = Vec::new();
+        data.write_all(&mut bytes)?;
+
+        let dir = destination
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let name = destination.file_name().unwrap_or_default().to_string_lossy();
+        let temp = dir.join(format!(".{}.{}.tmp", name, std::process::id()));
+
+        let written = write_synced(&temp, &bytes).and_then(|_| {
+            if self.backup {
+                backup(destination)?;
+            }
+            rename(&temp, destination)
+        });
+        if let Err(e) = written {
+            // Best effort, the write error is the one to report
+            let _ = remove_file(&temp);
+            return Err(e.into());
+        }
+        sync_dir(dir)?;
+        Ok(())
+    }
+}
+
+fn write_synced(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(bytes)?;
+    file.sync_all()
+}
+
+/// The first original is the one kept: a `.orig` left by a previous pass is never replaced
+fn backup(path: &Path) -> io::Result<()> {
+    let mut orig = path.as_os_str().to_owned();
+    orig.push(".orig");
+    let orig = PathBuf::from(orig);
+    if !path.exists() || orig.exists() {
+        return Ok(());
+    }
+    // The rename that follows leaves the original to the link
+    if hard_link(path, &orig).is_err() {
+        copy(path, &orig)?;
+        File::open(&orig)?.sync_all()?;
+    }
+    Ok(())
+}
+
+/// Persist the rename itself
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn layout_error(details: String) -> PulseError {
+    PulseError::new(
+        PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidInput, "Invalid output path")),
+        details,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(input: &str, output: &str) -> OutputLayout {
+        let root = std::env::temp_dir().join(format!("pulsedcm-layout-{}", std::process::id()));
+        OutputLayout::new(root.join(input), root.join(output), None, Confirmation::Yes, false)
+    }
+
+    #[test]
+    fn mirror_the_input_tree() {
+        let layout = layout("in", "out");
+        let input = layout.input_root.join("a/b.dcm");
+        let destination = layout.destination(&input, &InMemDicomObject::new_empty()).unwrap();
+        assert_eq!(destination, layout.output_root.join("a/b.dcm"));
+    }
+
+    #[test]
+    fn reject_inputs_outside_the_input_path() {
+        let layout = layout("in", "out");
+        let input = layout.output_root.join("elsewhere.dcm");
+        assert!(layout.destination(&input, &InMemDicomObject::new_empty()).is_err());
+    }
+}
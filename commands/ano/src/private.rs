@@ -0,0 +1,293 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use phf::*;
+use pulsedcm_core::{InMemDicomObject, InMemElement, Tag};
+
+/// Safe private attributes kept with `PrivateMode::KeepSafe`, by private creator.
+///
+/// Values are (group, element offset in the creator block), a subset of PS3.15 Table E.3.10-1
+/// covering the GE, Siemens and Philips headers needed to use the images (diffusion, table, scaling).
+static SAFE_PRIVATE: phf::Map<&'static str, &'static [(u16, u8)]> = phf_map! {
+    "GEMS_ACQU_01" => &[(0x0019, 0x23), (0x0019, 0x24), (0x0019, 0x27), (0x0019, 0x9E)],
+    "GEMS_PARM_01" => &[(0x0043, 0x27), (0x0043, 0x39), (0x0043, 0x6F)],
+    "GEMS_SERS_01" => &[(0x0025, 0x07)],
+    "SIEMENS MR HEADER" => &[
+        (0x0019, 0x08), (0x0019, 0x09), (0x0019, 0x0A), (0x0019, 0x0B),
+        (0x0019, 0x0C), (0x0019, 0x0D), (0x0019, 0x0E), (0x0019, 0x27),
+    ],
+    "SIEMENS CSA HEADER" => &[(0x0029, 0x08), (0x0029, 0x09), (0x0029, 0x18), (0x0029, 0x19)],
+    "Philips MR Imaging DD 001" => &[(0x2001, 0x03), (0x2001, 0x04)],
+    "Philips PET Private Group" => &[(0x7053, 0x00), (0x7053, 0x09)],
+};
+
+/// What to do with the private attributes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrivateMode {
+    Remove,   // Remove every private group
+    KeepSafe, // Keep only the safe private attributes
+    Keep,     // Keep every private attribute
+}
+
+impl FromStr for PrivateMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "remove" => Ok(Self::Remove),
+            "keep-safe" => Ok(Self::KeepSafe),
+            "keep" => Ok(Self::Keep),
+            other => Err(format!(
+                "Unknown private mode '{}', expected one of: remove, keep-safe, keep",
+                other
+            )),
+        }
+    }
+}
+
+/// Number of private attributes kept and removed for one private creator
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CreatorStats {
+    pub kept: usize,
+    pub removed: usize,
+}
+
+/// Run-wide private attributes handling, also recording the private creators encountered
+#[derive(Debug)]
+pub struct PrivateTags {
+    mode: PrivateMode,
+    creators: Mutex<BTreeMap<(u16, String), CreatorStats>>,
+}
+
+impl PrivateTags {
+    pub fn new(mode: PrivateMode) -> Self {
+        Self {
+            mode,
+            creators: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn mode(&self) -> PrivateMode {
+        self.mode
+    }
+
+    /// Apply the mode to the private attributes of one dataset level, sequence items
+    /// having their own private creators. Returns the removed elements.
+    pub fn process(&self, data: &mut InMemDicomObject) -> Vec<InMemElement> {
+        let private: Vec<Tag> = data
+            .iter()
+            .map(|elem| elem.header().tag)
+            .filter(|tag| is_private(*tag))
+            .collect();
+        if private.is_empty() {
+            return Vec::new();
+        }
+
+        let creators: HashMap<(u16, u8), String> = private
+            .iter()
+            .filter(|tag| is_private_creator(**tag))
+            .filter_map(|tag| {
+                let creator = data.get(*tag)?.to_str().ok()?;
+                Some(((tag.group(), tag.element() as u8), creator.trim().to_string()))
+            })
+            .collect();
+
+        let mut stats: BTreeMap<(u16, String), CreatorStats> = BTreeMap::new();
+        let mut kept_blocks = HashSet::new();
+        let mut to_remove = Vec::new();
+        for tag in private.iter().filter(|tag| !is_private_creator(**tag)) {
+            // Elements outside of a creator block (gggg,0001-0FFF) have no creator
+            let block = (tag.element() >= 0x1000).then(|| (tag.group(), (tag.element() >> 8) as u8));
+            let creator = block.and_then(|b| creators.get(&b));
+
+            let keep = match self.mode {
+                PrivateMode::Keep => true,
+                PrivateMode::Remove => false,
+                PrivateMode::KeepSafe => creator.is_some_and(|c| is_safe(c, *tag)),
+            };
+
+            let entry = stats
+                .entry((tag.group(), creator.cloned().unwrap_or_default()))
+                .or_default();
+            if keep {
+                entry.kept += 1;
+                kept_blocks.extend(block);
+            } else {
+                entry.removed += 1;
+                to_remove.push(*tag);
+            }
+        }
+
+        // A creator is only needed while one of its attributes is kept
+        to_remove.extend(
+            private
+                .iter()
+                .filter(|tag| is_private_creator(**tag))
+                .filter(|tag| !kept_blocks.contains(&(tag.group(), tag.element() as u8))),
+        );
+        let removed = to_remove.into_iter().filter_map(|tag| data.take(tag)).collect();
+
+        if let Ok(mut creators) = self.creators.lock() {
+            for (key, s) in stats {
+                let entry = creators.entry(key).or_default();
+                entry.kept += s.kept;
+                entry.removed += s.removed;
+            }
+        }
+        removed
+    }
+
+    /// Private creators encountered during the run, by group, an empty creator standing for
+    /// attributes without one
+    pub fn report(&self) -> Vec<((u16, String), CreatorStats)> {
+        self.creators
+            .lock()
+            .map(|c| c.iter().map(|(k, v)| (k.clone(), *v)).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn print_report(&self) {
+        let report = self.report();
+        if report.is_empty() {
+            return;
+        }
+        println!("Private creators encountered:");
+        for ((group, creator), stats) in report {
+            let creator = if creator.is_empty() { "<no creator>" } else { creator.as_str() };
+            println!(
+                "  ({:04X},xxxx) {:<32} {} kept, {} removed",
+                group, creator, stats.kept, stats.removed
+            );
+        }
+    }
+}
+
+/// Odd groups above the command and file meta groups
+pub fn is_private(tag: Tag) -> bool {
+    tag.group() % 2 == 1 && tag.group() > 0x0008 && tag.group() != 0xFFFF
+}
+
+fn is_private_creator(tag: Tag) -> bool {
+    is_private(tag) && (0x0010..=0x00FF).contains(&tag.element())
+}
+
+fn is_safe(creator: &str, tag: Tag) -> bool {
+    SAFE_PRIVATE
+        .get(creator)
+        .is_some_and(|elems| elems.contains(&(tag.group(), tag.element() as u8)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::{dicom_dictionary_std::tags, DataElement, PrimitiveValue, VR};
+
+    const SIEMENS: &str = "SIEMENS MR HEADER";
+    const GEMS: &str = "GEMS_ACQU_01";
+
+    /// Two creators in group 0019, Siemens in block 10 and GE in block 11, each with a safe and
+    /// an unsafe attribute, and an attribute outside of any block
+    fn dataset(with_safe_gems: bool) -> InMemDicomObject {
+        let lo = |group, element, value: &str| DataElement::new(Tag(group, element), VR::LO, PrimitiveValue::from(value));
+        let mut elements = vec![
+            DataElement::new(tags::PATIENT_NAME, VR::PN, PrimitiveValue::from("Doe^John")),
+            lo(0x0019, 0x0005, "no creator"),
+            lo(0x0019, 0x0010, SIEMENS),
+            lo(0x0019, 0x0011, GEMS),
+            lo(0x0019, 0x100C, "safe"),
+            lo(0x0019, 0x1010, "Doe^John"),
+            lo(0x0019, 0x1150, "Doe^John"),
+        ];
+        if with_safe_gems {
+            elements.push(lo(0x0019, 0x1123, "safe"));
+        }
+        InMemDicomObject::from_element_iter(elements)
+    }
+
+    fn tags_of(data: &InMemDicomObject) -> Vec<Tag> {
+        data.iter().map(|elem| elem.header().tag).collect()
+    }
+
+    fn stats(private: &PrivateTags) -> Vec<(String, usize, usize)> {
+        private
+            .report()
+            .into_iter()
+            .map(|((group, creator), s)| {
+                assert_eq!(group, 0x0019);
+                (creator, s.kept, s.removed)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_modes() {
+        assert_eq!(" Keep-Safe ".parse::<PrivateMode>(), Ok(PrivateMode::KeepSafe));
+        assert_eq!("remove".parse::<PrivateMode>(), Ok(PrivateMode::Remove));
+        assert_eq!("KEEP".parse::<PrivateMode>(), Ok(PrivateMode::Keep));
+        assert!("safe".parse::<PrivateMode>().is_err());
+    }
+
+    #[test]
+    fn safe_attributes_by_creator() {
+        assert!(is_safe(SIEMENS, Tag(0x0019, 0x100C)));
+        // The offset in the block counts, not the block the creator was given
+        assert!(is_safe(SIEMENS, Tag(0x0019, 0x110C)));
+        assert!(!is_safe(SIEMENS, Tag(0x0019, 0x1010)));
+        assert!(!is_safe(SIEMENS, Tag(0x0021, 0x100C)));
+        assert!(!is_safe("UNKNOWN", Tag(0x0019, 0x100C)));
+        assert!(!is_private(tags::PATIENT_NAME) && !is_private(Tag(0x0007, 0x0010)));
+        assert!(is_private_creator(Tag(0x0019, 0x0010)) && !is_private_creator(Tag(0x0019, 0x0005)));
+    }
+
+    #[test]
+    fn remove_every_private_attribute() {
+        let mut data = dataset(true);
+        let private = PrivateTags::new(PrivateMode::Remove);
+        assert_eq!(private.process(&mut data).len(), 7);
+        assert_eq!(tags_of(&data), [tags::PATIENT_NAME]);
+        assert_eq!(
+            stats(&private),
+            [("".to_string(), 0, 1), (GEMS.to_string(), 0, 2), (SIEMENS.to_string(), 0, 2)]
+        );
+    }
+
+    #[test]
+    fn keep_safe_attributes_with_their_creator() {
+        let mut data = dataset(true);
+        let private = PrivateTags::new(PrivateMode::KeepSafe);
+        let removed: Vec<Tag> = private.process(&mut data).iter().map(|e| e.header().tag).collect();
+        assert_eq!(removed, [Tag(0x0019, 0x0005), Tag(0x0019, 0x1010), Tag(0x0019, 0x1150)]);
+        assert_eq!(
+            tags_of(&data),
+            [tags::PATIENT_NAME, Tag(0x0019, 0x0010), Tag(0x0019, 0x0011), Tag(0x0019, 0x100C), Tag(0x0019, 0x1123)]
+        );
+        assert_eq!(
+            stats(&private),
+            [("".to_string(), 0, 1), (GEMS.to_string(), 1, 1), (SIEMENS.to_string(), 1, 1)]
+        );
+    }
+
+    #[test]
+    fn drop_creators_without_kept_attributes() {
+        let mut data = dataset(false);
+        let private = PrivateTags::new(PrivateMode::KeepSafe);
+        private.process(&mut data);
+        assert_eq!(tags_of(&data), [tags::PATIENT_NAME, Tag(0x0019, 0x0010), Tag(0x0019, 0x100C)]);
+    }
+
+    #[test]
+    fn keep_every_private_attribute() {
+        let mut data = dataset(true);
+        let private = PrivateTags::new(PrivateMode::Keep);
+        assert!(private.process(&mut data).is_empty());
+        assert_eq!(tags_of(&data), tags_of(&dataset(true)));
+
+        // Counts add up over the files of the run
+        private.process(&mut dataset(false));
+        assert_eq!(
+            stats(&private),
+            [("".to_string(), 2, 0), (GEMS.to_string(), 3, 0), (SIEMENS.to_string(), 4, 0)]
+        );
+    }
+}
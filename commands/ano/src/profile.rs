@@ -0,0 +1,238 @@
+use std::str::FromStr;
+
+use crate::models::{ActionCode, PolicyAction};
+
+/// Supplement 142 options applied on top of the Basic Application Level Confidentiality Profile.
+///
+/// Each enabled option overrides the basic action of the tags it covers (`DEID_HASH` columns).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub ret_sf_priv: bool,    // Retain Safe Private Option
+    pub ret_uids: bool,       // Retain UIDs Option
+    pub ret_dev_id: bool,     // Retain Device Identity Option
+    pub ret_inst_id: bool,    // Retain Institution Identity Option
+    pub ret_pt_char: bool,    // Retain Patient Characteristics Option
+    pub ret_lg_full_dt: bool, // Retain Longitudinal Temporal Information with Full Dates Option
+    pub ret_lg_mod_dt: bool,  // Retain Longitudinal Temporal Information with Modified Dates Option
+    pub cln_desc: bool,       // Clean Descriptors Option
+    pub cln_struc_cnt: bool,  // Clean Structured Content Option
+    pub cln_graph: bool,      // Clean Graphics Option
+    pub action: Option<ActionOverride>, // Forced action for every matched tag
+}
+
+/// Names accepted on the command line, in the same order as the `PolicyAction` columns
+pub const PROFILE_OPTIONS: [&str; 10] = [
+    "retain-safe-private",
+    "retain-uids",
+    "retain-device-id",
+    "retain-institution-id",
+    "retain-patient-chars",
+    "retain-long-full-dates",
+    "retain-long-modified-dates",
+    "clean-descriptors",
+    "clean-structured-content",
+    "clean-graphics",
+];
+
+impl Profile {
+    /// Enable every option enabled in `other` on top of this profile
+    pub fn merge(mut self, other: &Profile) -> Self {
+        self.ret_sf_priv |= other.ret_sf_priv;
+        self.ret_uids |= other.ret_uids;
+        self.ret_dev_id |= other.ret_dev_id;
+        self.ret_inst_id |= other.ret_inst_id;
+        self.ret_pt_char |= other.ret_pt_char;
+        self.ret_lg_full_dt |= other.ret_lg_full_dt;
+        self.ret_lg_mod_dt |= other.ret_lg_mod_dt;
+        self.cln_desc |= other.cln_desc;
+        self.cln_struc_cnt |= other.cln_struc_cnt;
+        self.cln_graph |= other.cln_graph;
+        if other.action.is_some() {
+            self.action = other.action;
+        }
+        self
+    }
+
+    /// Enable one option by its command line name
+    pub fn enable(&mut self, option: &str) -> Result<(), String> {
+        match option {
+            "retain-safe-private" => self.ret_sf_priv = true,
+            "retain-uids" => self.ret_uids = true,
+            "retain-device-id" => self.ret_dev_id = true,
+            "retain-institution-id" => self.ret_inst_id = true,
+            "retain-patient-chars" => self.ret_pt_char = true,
+            "retain-long-full-dates" => self.ret_lg_full_dt = true,
+            "retain-long-modified-dates" => self.ret_lg_mod_dt = true,
+            "clean-descriptors" => self.cln_desc = true,
+            "clean-structured-content" => self.cln_struc_cnt = true,
+            "clean-graphics" => self.cln_graph = true,
+            other => {
+                return Err(format!(
+                    "Unknown profile option '{}', expected one of: {}",
+                    other,
+                    PROFILE_OPTIONS.join(", ")
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Action to apply for a tag: the first enabled option (in column order) defining an
+    /// action for it wins, otherwise the basic profile action is used.
+    /// The action override then replaces anything that isn't kept, UIDs being
+    /// still remapped under `replace` so that references between files hold.
+    pub fn resolve<'a>(&self, policy: &'a PolicyAction) -> &'a ActionCode {
+        let action = self.resolve_options(policy);
+        match (self.action, action) {
+            (_, ActionCode::K) | (None, _) => action,
+            (Some(ActionOverride::Replace), ActionCode::U) => action,
+            (Some(forced), _) => forced.code(),
+        }
+    }
+
+    fn resolve_options<'a>(&self, policy: &'a PolicyAction) -> &'a ActionCode {
+        let options = [
+            (self.ret_sf_priv, &policy.ret_sf_priv),
+            (self.ret_uids, &policy.ret_uids),
+            (self.ret_dev_id, &policy.ret_dev_id),
+            (self.ret_inst_id, &policy.ret_inst_id),
+            (self.ret_pt_char, &policy.ret_pt_char),
+            (self.ret_lg_full_dt, &policy.ret_lg_full_dt),
+            (self.ret_lg_mod_dt, &policy.ret_lg_mod_dt),
+            (self.cln_desc, &policy.cln_desc),
+            (self.cln_struc_cnt, &policy.cln_struc_cnt),
+            (self.cln_graph, &policy.cln_graph),
+        ];
+        options
+            .into_iter()
+            .find_map(|(enabled, action)| if enabled { action.as_ref() } else { None })
+            .unwrap_or(&policy.basic)
+    }
+}
+
+impl FromStr for Profile {
+    type Err = String;
+
+    /// Comma-separated list of options, `none` alone means no option
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut profile = Profile::default();
+        for option in s.split(',').map(str::trim).filter(|o| !o.is_empty()) {
+            let option = option.to_lowercase();
+            if option == "none" {
+                continue;
+            }
+            profile.enable(&option)?;
+        }
+        Ok(profile)
+    }
+}
+
+/// Policy tiers, each one being a preset of Supplement 142 options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Remove only the patient PHI, keep device, institution, patient characteristics and dates
+    Basic,
+    /// Also remove institution and device information, dates are modified instead of kept
+    Moderate,
+    /// Basic Application Level Confidentiality Profile without any retention option
+    #[default]
+    Strict,
+}
+
+impl Policy {
+    pub fn profile(&self) -> Profile {
+        match self {
+            Self::Basic => Profile {
+                ret_dev_id: true,
+                ret_inst_id: true,
+                ret_pt_char: true,
+                ret_lg_full_dt: true,
+                ..Default::default()
+            },
+            Self::Moderate => Profile {
+                ret_pt_char: true,
+                ret_lg_mod_dt: true,
+                ..Default::default()
+            },
+            Self::Strict => Profile::default(),
+        }
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "basic" => Ok(Self::Basic),
+            "moderate" => Ok(Self::Moderate),
+            "strict" => Ok(Self::Strict),
+            other => Err(format!(
+                "Unknown policy '{}', expected one of: basic, moderate, strict",
+                other
+            )),
+        }
+    }
+}
+
+/// Action forced on every matched tag whatever the profile says, kept tags excepted
+/// (and remapped UIDs for `Replace`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActionOverride {
+    Replace, // D
+    Zero,    // Z
+    Remove,  // X
+}
+
+impl ActionOverride {
+    pub fn code(&self) -> &'static ActionCode {
+        match self {
+            Self::Replace => &ActionCode::D,
+            Self::Zero => &ActionCode::Z,
+            Self::Remove => &ActionCode::X,
+        }
+    }
+}
+
+impl FromStr for ActionOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "replace" => Ok(Self::Replace),
+            "zero" => Ok(Self::Zero),
+            "remove" => Ok(Self::Remove),
+            other => Err(format!(
+                "Unknown action '{}', expected one of: replace, zero, remove",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEID_HASH;
+
+    fn resolve(profile: &Profile, tag: (u16, u16)) -> &'static ActionCode {
+        profile.resolve(DEID_HASH.get(&tag).unwrap())
+    }
+
+    #[test]
+    fn replace_keeps_remapping_uids() {
+        let profile = Profile { action: Some(ActionOverride::Replace), ..Default::default() };
+        // SOP Instance UID
+        assert!(matches!(resolve(&profile, (0x0008, 0x0018)), ActionCode::U));
+        // Patient's Name
+        assert!(matches!(resolve(&profile, (0x0010, 0x0010)), ActionCode::D));
+    }
+
+    #[test]
+    fn other_overrides_replace_uids() {
+        let profile = Profile { action: Some(ActionOverride::Remove), ..Default::default() };
+        assert!(matches!(resolve(&profile, (0x0008, 0x0018)), ActionCode::X));
+        let profile = Profile { ret_uids: true, ..profile };
+        assert!(matches!(resolve(&profile, (0x0008, 0x0018)), ActionCode::K));
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use csv::{ReaderBuilder, Trim};
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, InMemDicomObject, PulseError, PulseErrorKind, Result,
+};
+
+use crate::uid::UidGenerator;
+
+/// Prefix of the subject IDs made by the keyed-hash generator
+const SUBJECT_PREFIX: &str = "SUBJ-";
+
+/// Where the pseudonyms come from, as given on the command line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PseudonymSource {
+    Hash,           // Keyed hash of the PatientID
+    Table(PathBuf), // CSV lookup table
+}
+
+impl FromStr for PseudonymSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Err("Expected `hash` or a CSV file".to_string()),
+            "hash" => Ok(Self::Hash),
+            path => Ok(Self::Table(PathBuf::from(path))),
+        }
+    }
+}
+
+/// New identity of a patient
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pseudonym {
+    pub id: String,
+    pub name: String,
+}
+
+/// Run-wide pseudonym generator, giving the same pseudonym to every file of a patient
+#[derive(Debug)]
+pub enum Pseudonymizer {
+    Hash,
+    Table(HashMap<String, Pseudonym>),
+}
+
+impl Pseudonymizer {
+    pub fn new(source: &PseudonymSource) -> Result<Self> {
+        match source {
+            PseudonymSource::Hash => Ok(Self::Hash),
+            PseudonymSource::Table(path) => Self::from_file(path),
+        }
+    }
+
+    /// CSV with a header and the columns: original PatientID, new PatientID and optionally
+    /// new PatientName (the new PatientID when missing or empty)
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let mut rdr = ReaderBuilder::new()
+            .flexible(true)
+            .trim(Trim::All)
+            .from_path(path)?;
+        let mut table = HashMap::new();
+        for (line, record) in rdr.records().enumerate() {
+            let record = record?;
+            let (Some(original), Some(id)) = (record.get(0), record.get(1)) else {
+                return Err(invalid_table(path, line + 2));
+            };
+            if original.is_empty() || id.is_empty() {
+                return Err(invalid_table(path, line + 2));
+            }
+            let name = record.get(2).filter(|n| !n.is_empty()).unwrap_or(id);
+            table.insert(
+                original.to_string(),
+                Pseudonym { id: id.to_string(), name: name.to_string() },
+            );
+        }
+        Ok(Self::Table(table))
+    }
+
+    /// Pseudonym of the patient of a file, read before any modification of the dataset
+    pub fn pseudonym(&self, uids: &UidGenerator, data: &InMemDicomObject) -> Result<Pseudonym> {
+        let patient_id = data
+            .get(tags::PATIENT_ID)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+
+        match self {
+            Self::Hash => {
+                let digest = uids.digest(&format!("PatientID:{}", patient_id));
+                let hex: String = digest[..6].iter().map(|b| format!("{:02X}", b)).collect();
+                let id = format!("{}{}", SUBJECT_PREFIX, hex);
+                Ok(Pseudonym { name: id.clone(), id })
+            }
+            Self::Table(table) => table.get(&patient_id).cloned().ok_or_else(|| {
+                PulseError::new(
+                    PulseErrorKind::IO(io::Error::new(io::ErrorKind::NotFound, "Unknown patient")),
+                    format!("PatientID '{}' isn't in the pseudonym table", patient_id),
+                )
+            }),
+        }
+    }
+}
+
+fn invalid_table(path: &Path, line: usize) -> PulseError {
+    PulseError::new(
+        PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidData, "Invalid pseudonym table")),
+        format!(
+            "{}:{}: expected an original and a new PatientID",
+            path.display(),
+            line
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::{DataElement, PrimitiveValue, VR};
+
+    fn patient(id: &str) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter([DataElement::new(tags::PATIENT_ID, VR::LO, PrimitiveValue::from(id))])
+    }
+
+    fn table(content: &str) -> Result<Pseudonymizer> {
+        let path = std::env::temp_dir().join(format!("pulsedcm-pseudonyms-{}-{}.csv", std::process::id(), content.len()));
+        std::fs::write(&path, content).unwrap();
+        let table = Pseudonymizer::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        table
+    }
+
+    #[test]
+    fn hash_is_keyed() {
+        let uids = UidGenerator::new("secret");
+        let pseudonym = Pseudonymizer::Hash.pseudonym(&uids, &patient("AB12")).unwrap();
+        assert!(pseudonym.id.starts_with(SUBJECT_PREFIX));
+        assert_eq!(pseudonym.id.len(), SUBJECT_PREFIX.len() + 12);
+        assert_eq!(pseudonym.name, pseudonym.id);
+        assert_eq!(pseudonym, Pseudonymizer::Hash.pseudonym(&UidGenerator::new("secret"), &patient("AB12 ")).unwrap());
+        assert_ne!(pseudonym, Pseudonymizer::Hash.pseudonym(&UidGenerator::new("other"), &patient("AB12")).unwrap());
+        assert_ne!(pseudonym, Pseudonymizer::Hash.pseudonym(&uids, &patient("AB13")).unwrap());
+    }
+
+    #[test]
+    fn lookup_table() {
+        let uids = UidGenerator::new("secret");
+        let table = table("original,id,name\nAB12, P001 ,Doe^Jane\nAB13,P002\nAB14,P003,\n").unwrap();
+        let lookup = |id| table.pseudonym(&uids, &patient(id));
+        assert_eq!(lookup("AB12").unwrap(), Pseudonym { id: "P001".into(), name: "Doe^Jane".into() });
+        assert_eq!(lookup("AB13").unwrap(), Pseudonym { id: "P002".into(), name: "P002".into() });
+        assert_eq!(lookup("AB14").unwrap().name, "P003");
+        assert!(lookup("AB15").is_err());
+    }
+
+    #[test]
+    fn reject_invalid_tables() {
+        assert!(table("original,id\nAB12\n").is_err());
+        assert!(table("original,id\nAB12,\n").is_err());
+        assert!("".parse::<PseudonymSource>().is_err());
+        assert_eq!("hash".parse(), Ok(PseudonymSource::Hash));
+    }
+}
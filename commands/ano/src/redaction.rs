@@ -0,0 +1,567 @@
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, dicom_transfer_syntax_registry::entries, DataDictionary,
+    DataElement, FileDicomObject, InMemDicomObject, PixelDecoder, PrimitiveValue, PulseError,
+    PulseErrorKind, Result, StandardDataDictionary, Tag, Transcode, TransferSyntaxIndex,
+    TransferSyntaxRegistry, VR,
+};
+
+/// Rectangle to blank, in pixels from the top left corner of each frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Method {
+    Equals,
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+#[derive(Debug)]
+struct Condition {
+    tag: Tag,
+    method: Method,
+    ignore_case: bool,
+    negate: bool,
+    value: String,
+}
+
+impl Condition {
+    fn matches(&self, data: &InMemDicomObject) -> bool {
+        // Missing attributes are compared as empty strings
+        let actual = data
+            .get(self.tag)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim_end_matches(['\0', ' ']).trim_start().to_string())
+            .unwrap_or_default();
+        let (actual, expected) = if self.ignore_case {
+            (actual.to_lowercase(), self.value.to_lowercase())
+        } else {
+            (actual, self.value.clone())
+        };
+        let found = match self.method {
+            Method::Equals => actual == expected,
+            Method::Contains => actual.contains(&expected),
+            Method::StartsWith => actual.starts_with(&expected),
+            Method::EndsWith => actual.ends_with(&expected),
+        };
+        found != self.negate
+    }
+}
+
+#[derive(Debug)]
+struct Signature {
+    // Alternatives (`+`) of conditions that must all match (`*`)
+    conditions: Vec<Vec<Condition>>,
+    regions: Vec<Region>,
+}
+
+/// Pixel redaction rules, in the CTP DicomPixelAnonymizer script format:
+///
+/// ```text
+/// # Comment
+/// { Modality.equals("US") * Manufacturer.containsIgnoreCase("acme") * Rows.equals("600") }
+/// (0,0,800,60) (0,560,800,40)
+/// ```
+///
+/// Each signature is a condition on the attributes of the file followed by the
+/// (x,y,width,height) regions to blank. The first matching signature is used.
+#[derive(Debug, Default)]
+pub struct RedactionRules {
+    signatures: Vec<Signature>,
+}
+
+impl RedactionRules {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = read_to_string(path)?;
+        content.parse().map_err(|e: String| {
+            PulseError::new(
+                PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidData, e)),
+                format!("Invalid redaction rules in {}", path.display()),
+            )
+        })
+    }
+
+    /// Regions of the first signature matching the dataset, `None` if no signature matches.
+    /// A matching signature without region means there is nothing to blank.
+    pub fn regions(&self, data: &InMemDicomObject) -> Option<&[Region]> {
+        self.signatures
+            .iter()
+            .find(|s| {
+                s.conditions
+                    .iter()
+                    .any(|all| all.iter().all(|c| c.matches(data)))
+            })
+            .map(|s| s.regions.as_slice())
+    }
+}
+
+impl std::str::FromStr for RedactionRules {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let content: String = s
+            .lines()
+            .filter(|l| !l.trim_start().starts_with('#'))
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        let mut signatures = Vec::new();
+        let mut rest = content.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .map(|e| start + e)
+                .ok_or("Unclosed signature, missing '}'")?;
+            let conditions = parse_conditions(&rest[start + 1..end])?;
+            rest = &rest[end + 1..];
+            let next = rest.find('{').unwrap_or(rest.len());
+            let regions = parse_regions(&rest[..next])?;
+            rest = &rest[next..];
+            signatures.push(Signature { conditions, regions });
+        }
+        Ok(Self { signatures })
+    }
+}
+
+fn parse_conditions(s: &str) -> std::result::Result<Vec<Vec<Condition>>, String> {
+    split_unquoted(s, '+')
+        .iter()
+        .map(|any| {
+            split_unquoted(any, '*')
+                .iter()
+                .map(|c| parse_condition(c))
+                .collect()
+        })
+        .collect()
+}
+
+/// `[!]Keyword.method("value")`, the keyword can also be a `[gggg,eeee]` tag
+fn parse_condition(s: &str) -> std::result::Result<Condition, String> {
+    let s = s.trim();
+    let (negate, s) = match s.strip_prefix('!') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, s),
+    };
+    let invalid = || format!("Invalid condition '{}'", s);
+
+    let open = s.find('(').ok_or_else(invalid)?;
+    let (path, args) = (&s[..open], &s[open..]);
+    let dot = path.rfind('.').ok_or_else(invalid)?;
+    let (attribute, method) = (path[..dot].trim(), path[dot + 1..].trim());
+
+    let value = args
+        .trim()
+        .strip_prefix('(')
+        .and_then(|a| a.strip_suffix(')'))
+        .map(str::trim)
+        .and_then(|a| a.strip_prefix('"'))
+        .and_then(|a| a.strip_suffix('"'))
+        .ok_or_else(invalid)?;
+
+    let (method, ignore_case) = match method {
+        "equals" => (Method::Equals, false),
+        "equalsIgnoreCase" => (Method::Equals, true),
+        "contains" => (Method::Contains, false),
+        "containsIgnoreCase" => (Method::Contains, true),
+        "startsWith" => (Method::StartsWith, false),
+        "startsWithIgnoreCase" => (Method::StartsWith, true),
+        "endsWith" => (Method::EndsWith, false),
+        "endsWithIgnoreCase" => (Method::EndsWith, true),
+        other => return Err(format!("Unknown method '{}' in '{}'", other, s)),
+    };
+
+    Ok(Condition {
+        tag: parse_attribute(attribute).ok_or_else(|| format!("Unknown attribute '{}'", attribute))?,
+        method,
+        ignore_case,
+        negate,
+        value: value.to_string(),
+    })
+}
+
+fn parse_attribute(s: &str) -> Option<Tag> {
+    match s.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        Some(tag) => {
+            let (group, element) = tag.split_once(',')?;
+            Some(Tag(
+                u16::from_str_radix(group.trim(), 16).ok()?,
+                u16::from_str_radix(element.trim(), 16).ok()?,
+            ))
+        }
+        None => StandardDataDictionary.by_name(s).map(|e| e.tag.inner()),
+    }
+}
+
+/// Every `(x,y,width,height)` group of the text
+fn parse_regions(s: &str) -> std::result::Result<Vec<Region>, String> {
+    let mut regions = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find('(') {
+        let end = rest[start..]
+            .find(')')
+            .map(|e| start + e)
+            .ok_or("Unclosed region, missing ')'")?;
+        let values: Vec<u32> = rest[start + 1..end]
+            .split(',')
+            .map(|v| v.trim().parse::<u32>())
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| format!("Invalid region '{}'", &rest[start..=end]))?;
+        match values[..] {
+            [x, y, width, height] => regions.push(Region { x, y, width, height }),
+            _ => return Err(format!("Invalid region '{}'", &rest[start..=end])),
+        }
+        rest = &rest[end + 1..];
+    }
+    Ok(regions)
+}
+
+fn split_unquoted(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quoted = false;
+    let mut last = 0;
+    for (i, c) in s.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == sep && !quoted {
+            parts.push(&s[last..i]);
+            last = i + c.len_utf8();
+        }
+    }
+    parts.push(&s[last..]);
+    parts
+}
+
+/// Layout of native pixel data
+struct Geometry {
+    rows: usize,
+    columns: usize,
+    samples: usize,
+    bytes_per_sample: usize,
+    planar: bool,
+    frames: usize,
+    fill: [u32; 3],
+}
+
+impl Geometry {
+    fn frame_len(&self) -> usize {
+        self.rows * self.columns * self.samples * self.bytes_per_sample
+    }
+
+    fn redact(&self, pixels: &mut [u8], regions: &[Region]) -> Result<()> {
+        let frame_len = self.frame_len();
+        if frame_len == 0 {
+            return Err(PulseError::new(
+                PulseErrorKind::UnsupportedPixelData,
+                "Can't redact pixel data without Rows and Columns",
+            ));
+        }
+        // Frames left unredacted would leak what the regions hide
+        if pixels.len() < self.frames * frame_len {
+            return Err(PulseError::new(
+                PulseErrorKind::UnsupportedPixelData,
+                format!(
+                    "Pixel data of {} bytes is shorter than {} frame(s) of {} bytes",
+                    pixels.len(),
+                    self.frames,
+                    frame_len
+                ),
+            ));
+        }
+        for frame in pixels.chunks_exact_mut(frame_len).take(self.frames) {
+            for region in regions {
+                let x_end = (region.x as usize + region.width as usize).min(self.columns);
+                let y_end = (region.y as usize + region.height as usize).min(self.rows);
+                for y in (region.y as usize).min(y_end)..y_end {
+                    for x in (region.x as usize).min(x_end)..x_end {
+                        for sample in 0..self.samples {
+                            let index = if self.planar {
+                                sample * self.rows * self.columns + y * self.columns + x
+                            } else {
+                                (y * self.columns + x) * self.samples + sample
+                            };
+                            let offset = index * self.bytes_per_sample;
+                            let fill = self.fill[sample.min(2)].to_le_bytes();
+                            frame[offset..offset + self.bytes_per_sample]
+                                .copy_from_slice(&fill[..self.bytes_per_sample]);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_bits_allocated(bits_allocated: u16) -> Result<()> {
+    if !bits_allocated.is_multiple_of(8) || bits_allocated == 0 || bits_allocated > 32 {
+        return Err(PulseError::new(
+            PulseErrorKind::UnsupportedPixelData,
+            format!("Can't redact pixel data of {} bits allocated", bits_allocated),
+        ));
+    }
+    Ok(())
+}
+
+/// Sample values displayed as black for the photometric interpretation
+fn fill_values(photometric: &str, bits_allocated: u16, bits_stored: u16, signed: bool) -> [u32; 3] {
+    let bits_stored = bits_stored.clamp(1, bits_allocated) as u32;
+    let mask = if bits_allocated >= 32 { u32::MAX } else { (1u32 << bits_allocated) - 1 };
+    let (min, max) = if signed {
+        ((-(1i64 << (bits_stored - 1))) as u32 & mask, (1u32 << (bits_stored - 1)) - 1)
+    } else {
+        (0, ((1u64 << bits_stored) - 1) as u32)
+    };
+    match photometric {
+        "MONOCHROME1" => [max; 3],
+        p if p.starts_with("YBR") => [0, 1 << (bits_stored - 1), 1 << (bits_stored - 1)],
+        _ => [min; 3],
+    }
+}
+
+/// Blank the regions in every frame, re-encoding the pixel data in the original transfer
+/// syntax when an encoder is available, in Explicit VR Little Endian otherwise.
+pub fn redact(obj: &mut FileDicomObject<InMemDicomObject>, regions: &[Region]) -> Result<()> {
+    let ts_uid = obj.meta().transfer_syntax().to_string();
+    let ts = TransferSyntaxRegistry.get(&ts_uid).ok_or_else(|| {
+        PulseError::new(
+            PulseErrorKind::UnsupportedPixelData,
+            format!("Unknown transfer syntax {}", ts_uid),
+        )
+    })?;
+
+    if ts.is_codec_free() {
+        if ts_uid == entries::EXPLICIT_VR_BIG_ENDIAN.uid() {
+            return Err(PulseError::new(
+                PulseErrorKind::UnsupportedPixelData,
+                "Can't redact big endian pixel data",
+            ));
+        }
+        return redact_native(obj, regions);
+    }
+
+    let decoded = obj.decode_pixel_data()?;
+    let bits_allocated = decoded.bits_allocated();
+    check_bits_allocated(bits_allocated)?;
+    let geometry = Geometry {
+        rows: decoded.rows() as usize,
+        columns: decoded.columns() as usize,
+        samples: decoded.samples_per_pixel().max(1) as usize,
+        bytes_per_sample: (bits_allocated / 8) as usize,
+        planar: decoded.planar_configuration() as u16 == 1,
+        frames: decoded.number_of_frames().max(1) as usize,
+        fill: fill_values(
+            decoded.photometric_interpretation().as_str(),
+            bits_allocated,
+            decoded.bits_stored(),
+            decoded.pixel_representation() as u16 == 1,
+        ),
+    };
+    let photometric = decoded.photometric_interpretation().as_str().to_string();
+    let planar = decoded.planar_configuration() as u16;
+    let mut pixels = decoded.data().to_vec();
+    drop(decoded);
+    geometry.redact(&mut pixels, regions)?;
+
+    // Native pixel data as decoded, then encoded back if possible
+    let vr = if bits_allocated > 8 { VR::OW } else { VR::OB };
+    obj.put(DataElement::new(tags::PIXEL_DATA, vr, PrimitiveValue::U8(pixels.into())));
+    obj.put_str(tags::PHOTOMETRIC_INTERPRETATION, VR::CS, photometric);
+    if geometry.samples > 1 {
+        obj.put(DataElement::new(
+            tags::PLANAR_CONFIGURATION,
+            VR::US,
+            PrimitiveValue::from(planar),
+        ));
+    }
+    obj.meta_mut().set_transfer_syntax(&entries::EXPLICIT_VR_LITTLE_ENDIAN);
+
+    if ts.is_fully_supported() {
+        let mut encoded = obj.clone();
+        match encoded.transcode(ts) {
+            Ok(()) => *obj = encoded,
+            Err(e) => eprintln!(
+                "Couldn't re-encode redacted pixel data in {}, written as Explicit VR Little Endian: {}",
+                ts.name(),
+                e
+            ),
+        }
+    } else {
+        eprintln!(
+            "No encoder for {}, redacted pixel data written as Explicit VR Little Endian",
+            ts.name()
+        );
+    }
+    Ok(())
+}
+
+fn redact_native(obj: &mut FileDicomObject<InMemDicomObject>, regions: &[Region]) -> Result<()> {
+    let read_u32 = |tag: Tag, default: u32| {
+        obj.get(tag)
+            .and_then(|e| e.to_str().ok())
+            .and_then(|s| s.trim().parse::<u32>().ok())
+            .unwrap_or(default)
+    };
+    let photometric = obj
+        .get(tags::PHOTOMETRIC_INTERPRETATION)
+        .and_then(|e| e.to_str().ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default();
+    let bits_allocated = read_u32(tags::BITS_ALLOCATED, 8) as u16;
+    check_bits_allocated(bits_allocated)?;
+    let geometry = Geometry {
+        rows: read_u32(tags::ROWS, 0) as usize,
+        columns: read_u32(tags::COLUMNS, 0) as usize,
+        samples: read_u32(tags::SAMPLES_PER_PIXEL, 1).max(1) as usize,
+        bytes_per_sample: (bits_allocated / 8) as usize,
+        planar: read_u32(tags::PLANAR_CONFIGURATION, 0) == 1,
+        frames: read_u32(tags::NUMBER_OF_FRAMES, 1).max(1) as usize,
+        fill: fill_values(
+            &photometric,
+            bits_allocated,
+            read_u32(tags::BITS_STORED, bits_allocated as u32) as u16,
+            read_u32(tags::PIXEL_REPRESENTATION, 0) == 1,
+        ),
+    };
+
+    let Some((vr, pixels)) = obj
+        .get(tags::PIXEL_DATA)
+        .and_then(|e| Some((e.vr(), e.value().primitive()?.to_bytes())))
+    else {
+        return Err(PulseError::new(
+            PulseErrorKind::UnsupportedPixelData,
+            "No native pixel data to redact",
+        ));
+    };
+    let mut pixels = pixels.into_owned();
+    geometry.redact(&mut pixels, regions)?;
+    obj.put(DataElement::new(tags::PIXEL_DATA, vr, PrimitiveValue::U8(pixels.into())));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(elements: &[(Tag, VR, &str)]) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter(
+            elements.iter().map(|(tag, vr, value)| DataElement::new(*tag, *vr, PrimitiveValue::from(*value))),
+        )
+    }
+
+    fn geometry(rows: usize, columns: usize, samples: usize, bytes_per_sample: usize, frames: usize) -> Geometry {
+        Geometry {
+            rows,
+            columns,
+            samples,
+            bytes_per_sample,
+            planar: false,
+            frames,
+            fill: [0; 3],
+        }
+    }
+
+    const RULES: &str = r#"
+        # Ultrasound burned-in header
+        { Modality.equals("US") * Manufacturer.containsIgnoreCase("acme") + [0008,0070].startsWith("Other") }
+        (0,0,4,1) (1,2,2,1)
+        { !Modality.equals("US") }
+    "#;
+
+    #[test]
+    fn parse_signatures() {
+        let rules: RedactionRules = RULES.parse().unwrap();
+        assert_eq!(rules.signatures.len(), 2);
+        assert_eq!(rules.signatures[0].conditions.len(), 2);
+        assert_eq!(rules.signatures[0].conditions[0].len(), 2);
+        assert_eq!(rules.signatures[0].conditions[1][0].tag, tags::MANUFACTURER);
+        assert_eq!(
+            rules.signatures[0].regions,
+            [Region { x: 0, y: 0, width: 4, height: 1 }, Region { x: 1, y: 2, width: 2, height: 1 }]
+        );
+        assert!(rules.signatures[1].regions.is_empty());
+    }
+
+    #[test]
+    fn match_signatures() {
+        let rules: RedactionRules = RULES.parse().unwrap();
+        let us = data(&[(tags::MODALITY, VR::CS, "US"), (tags::MANUFACTURER, VR::LO, "ACME Corp")]);
+        assert_eq!(rules.regions(&us).map(<[Region]>::len), Some(2));
+        let other = data(&[(tags::MODALITY, VR::CS, "US"), (tags::MANUFACTURER, VR::LO, "Other")]);
+        assert_eq!(rules.regions(&other).map(<[Region]>::len), Some(2));
+        // Second signature, nothing to blank
+        let ct = data(&[(tags::MODALITY, VR::CS, "CT")]);
+        assert_eq!(rules.regions(&ct).map(<[Region]>::len), Some(0));
+        let unmatched = data(&[(tags::MODALITY, VR::CS, "US"), (tags::MANUFACTURER, VR::LO, "Unknown")]);
+        assert!(rules.regions(&unmatched).is_none());
+    }
+
+    #[test]
+    fn reject_invalid_rules() {
+        for rules in [
+            r#"{ Modality.equals("US") "#,
+            r#"{ Modality.matches("US") }"#,
+            r#"{ NotAKeyword.equals("US") }"#,
+            r#"{ Modality.equals(US) }"#,
+            r#"{ Modality.equals("US") } (0,0,10)"#,
+            r#"{ Modality.equals("US") } (0,0,10,x)"#,
+        ] {
+            assert!(rules.parse::<RedactionRules>().is_err(), "{}", rules);
+        }
+    }
+
+    #[test]
+    fn redact_every_frame() {
+        // 2 frames of 3x4, 16 bits
+        let mut pixels = vec![0xFFu8; 2 * 3 * 4 * 2];
+        let region = Region { x: 1, y: 1, width: 10, height: 1 };
+        geometry(3, 4, 1, 2, 2).redact(&mut pixels, &[region]).unwrap();
+
+        for frame in pixels.chunks_exact(3 * 4 * 2) {
+            for (index, sample) in frame.chunks_exact(2).enumerate() {
+                let (x, y) = (index % 4, index / 4);
+                let blank = y == 1 && x >= 1;
+                assert_eq!(sample, if blank { [0, 0] } else { [0xFF, 0xFF] }, "{} {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn redact_planar_rgb() {
+        let mut geometry = geometry(2, 2, 3, 1, 1);
+        geometry.planar = true;
+        geometry.fill = [1, 2, 3];
+        let mut pixels = vec![0xFFu8; 12];
+        geometry.redact(&mut pixels, &[Region { x: 0, y: 0, width: 1, height: 1 }]).unwrap();
+        assert_eq!(pixels, [1, 0xFF, 0xFF, 0xFF, 2, 0xFF, 0xFF, 0xFF, 3, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn reject_incomplete_pixel_data() {
+        let region = [Region { x: 0, y: 0, width: 1, height: 1 }];
+        // No Rows or Columns
+        assert!(geometry(0, 4, 1, 1, 1).redact(&mut [0u8; 4], &region).is_err());
+        assert!(geometry(4, 0, 1, 1, 1).redact(&mut [0u8; 4], &region).is_err());
+        // Missing last frame, short frame
+        assert!(geometry(2, 2, 1, 1, 2).redact(&mut [0u8; 4], &region).is_err());
+        assert!(geometry(2, 2, 1, 1, 2).redact(&mut [0u8; 7], &region).is_err());
+        assert!(geometry(2, 2, 1, 1, 2).redact(&mut [0u8; 8], &region).is_ok());
+    }
+
+    #[test]
+    fn fill_black() {
+        assert_eq!(fill_values("MONOCHROME2", 16, 12, false), [0; 3]);
+        assert_eq!(fill_values("MONOCHROME1", 16, 12, false), [4095; 3]);
+        assert_eq!(fill_values("MONOCHROME2", 16, 12, true), [0xF800; 3]);
+        assert_eq!(fill_values("YBR_FULL", 8, 8, false), [0, 128, 128]);
+    }
+}
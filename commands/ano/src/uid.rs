@@ -0,0 +1,104 @@
+use pulsedcm_core::Result;
+use sha2::{Digest, Sha256};
+
+/// Root used for every generated UID (UUID derived UIDs, PS3.5 B.2)
+const UID_ROOT: &str = "2.25.";
+
+/// Deterministic UID remapper used by the `U` action.
+///
+/// The new UID is derived from a salted SHA-256 of the original one, so the same
+/// original UID always gives the same new UID for a given secret, whatever the
+/// file or the thread processing it.
+#[derive(Clone, Debug)]
+pub struct UidGenerator {
+    secret: String,
+}
+
+impl UidGenerator {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self { secret: secret.into() }
+    }
+
+    /// Use a secret only valid for this run: UIDs stay consistent between the files
+    /// of the run but can't be reproduced afterwards. Drawn from the OS random source,
+    /// a secret guessable from the run time would let the UIDs be traced back.
+    pub fn random() -> Result<Self> {
+        let mut secret = [0u8; 32];
+        getrandom::fill(&mut secret).map_err(std::io::Error::from)?;
+        Ok(Self::new(secret.iter().map(|b| format!("{:02x}", b)).collect::<String>()))
+    }
+
+    /// Salted SHA-256 of a value, also used to derive other pseudonymous values of the run
+    pub fn digest(&self, value: &str) -> [u8; 32] {
+        self.digest_bytes(value.as_bytes())
+    }
+
+    pub fn digest_bytes(&self, value: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.secret.as_bytes());
+        // Separator so that secret/value boundaries can't be shifted
+        hasher.update([0u8]);
+        hasher.update(value);
+        hasher.finalize().into()
+    }
+
+    pub fn remap(&self, uid: &str) -> String {
+        let digest = self.digest(uid.trim_end_matches(['\0', ' ']));
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        // Set the UUID version (4) and variant (RFC 4122) bits
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+        format!("{}{}", UID_ROOT, u128::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UID: &str = "1.2.840.113619.2.55.3.604688119.969.1268071029.320";
+
+    fn is_valid_uid(uid: &str) -> bool {
+        uid.len() <= 64
+            && uid.split('.').all(|part| {
+                !part.is_empty()
+                    && part.bytes().all(|b| b.is_ascii_digit())
+                    && (part == "0" || !part.starts_with('0'))
+            })
+    }
+
+    #[test]
+    fn remap_is_stable_for_a_secret() {
+        let uids = UidGenerator::new("secret");
+        assert_eq!(uids.remap(UID), UidGenerator::new("secret").remap(UID));
+        // Padding of the stored value doesn't change the UID
+        assert_eq!(uids.remap(UID), uids.remap(&format!("{}\0", UID)));
+    }
+
+    #[test]
+    fn remap_depends_on_the_secret() {
+        assert_ne!(UidGenerator::new("secret").remap(UID), UidGenerator::new("other").remap(UID));
+        assert_ne!(UidGenerator::new("secret").remap(UID), UidGenerator::new("secret").remap("1.2.3"));
+    }
+
+    #[test]
+    fn random_secrets_differ() {
+        let (first, second) = (UidGenerator::random().unwrap(), UidGenerator::random().unwrap());
+        assert_eq!(first.secret.len(), 64);
+        assert_ne!(first.secret, second.secret);
+    }
+
+    #[test]
+    fn remap_gives_valid_uids() {
+        for secret in ["secret", "other", ""] {
+            for uid in [UID, "1.2.3", ""] {
+                let remapped = UidGenerator::new(secret).remap(uid);
+                assert!(remapped.starts_with(UID_ROOT));
+                assert!(is_valid_uid(&remapped), "{}", remapped);
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use csv::Writer;
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, is_phi_tag, is_warning_tag, InMemDicomObject, OpenFileOptions,
+    PrimitiveValue, Result, Tag, VR,
+};
+use regex::{Regex, RegexBuilder};
+
+/// Identifying strings searched below this length give too many false positives
+const MIN_MATCH_LEN: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Warning, // To be reviewed
+    Fail,    // PHI left in the file
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Warning => "WARNING",
+            Self::Fail => "FAIL",
+        }
+    }
+}
+
+/// One suspicious element of an output file
+#[derive(Clone, Debug)]
+pub struct Finding {
+    pub location: String, // Tag, prefixed by the sequence items it is nested in
+    pub severity: Severity,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileReport {
+    pub path: PathBuf,
+    pub findings: Vec<Finding>,
+}
+
+impl FileReport {
+    pub fn passed(&self) -> bool {
+        self.findings.iter().all(|f| f.severity != Severity::Fail)
+    }
+}
+
+/// What has to be gone from the output, taken from the source file before it's de-identified
+#[derive(Debug)]
+pub struct Original {
+    /// Values of the PHI attributes, at any depth
+    values: HashMap<Tag, HashSet<String>>,
+    identifiers: Option<Regex>,
+}
+
+impl Original {
+    pub fn open(path: &Path) -> Result<Self> {
+        let data = OpenFileOptions::new()
+            .read_until(tags::PIXEL_DATA)
+            .open_file(path)?;
+        Ok(Self::new(&data))
+    }
+
+    pub fn new(data: &InMemDicomObject) -> Self {
+        let mut values = HashMap::new();
+        collect_values(data, &mut values);
+        Self {
+            values,
+            identifiers: identifiers_regex(data),
+        }
+    }
+
+    fn is_original(&self, tag: Tag, value: &str) -> bool {
+        self.values.get(&tag).is_some_and(|values| values.contains(value))
+    }
+}
+
+fn collect_values(data: &InMemDicomObject, values: &mut HashMap<Tag, HashSet<String>>) {
+    for elem in data.iter() {
+        let tag = elem.header().tag;
+        if let Some(items) = elem.items() {
+            items.iter().for_each(|item| collect_values(item, values));
+            continue;
+        }
+        if !is_phi_tag(tag) {
+            continue;
+        }
+        let Some(value) = text_value(elem.vr(), elem.value().primitive()) else { continue };
+        let value = value.trim();
+        if !value.is_empty() {
+            values.entry(tag).or_default().insert(value.to_string());
+        }
+    }
+}
+
+/// Patient name parts as words, patient ID and birth date (with or without separators)
+fn identifiers_regex(data: &InMemDicomObject) -> Option<Regex> {
+    let value = |tag| {
+        data.get(tag)
+            .and_then(|e| e.to_str().ok())
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+    let mut alternatives = Vec::new();
+
+    let names: Vec<String> = value(tags::PATIENT_NAME)
+        .split(|c: char| c == '^' || c == '=' || c.is_whitespace())
+        .filter(|part| part.chars().count() >= MIN_MATCH_LEN)
+        .map(regex::escape)
+        .collect();
+    if !names.is_empty() {
+        alternatives.push(format!(r"\b(?:{})\b", names.join("|")));
+    }
+
+    let patient_id = value(tags::PATIENT_ID);
+    if patient_id.chars().count() >= MIN_MATCH_LEN {
+        alternatives.push(format!(r"(?:^|[^0-9A-Za-z]){}(?:$|[^0-9A-Za-z])", regex::escape(&patient_id)));
+    }
+
+    let birth_date = value(tags::PATIENT_BIRTH_DATE);
+    if birth_date.len() == 8 && birth_date.is_ascii() {
+        let (y, md) = birth_date.split_at(4);
+        let (m, d) = md.split_at(2);
+        alternatives.push(format!(r"{y}[-/.]?{m}[-/.]?{d}|{d}[-/.]{m}[-/.]{y}|{m}[-/.]{d}[-/.]{y}"));
+    }
+
+    if alternatives.is_empty() {
+        return None;
+    }
+    RegexBuilder::new(&alternatives.join("|"))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+/// Re-open a written file and look for what's left of its source identity
+pub fn verify_file(original: &Original, path: &Path) -> Result<FileReport> {
+    let data = OpenFileOptions::new()
+        .read_until(tags::PIXEL_DATA)
+        .open_file(path)?;
+    let mut findings = Vec::new();
+    scan_dataset(&data, original, "", &mut findings);
+    Ok(FileReport {
+        path: path.to_path_buf(),
+        findings,
+    })
+}
+
+fn scan_dataset(data: &InMemDicomObject, original: &Original, prefix: &str, findings: &mut Vec<Finding>) {
+    for elem in data.iter() {
+        let tag = elem.header().tag;
+        let location = format!("{}({:04X},{:04X})", prefix, tag.group(), tag.element());
+
+        if let Some(items) = elem.items() {
+            for (i, item) in items.iter().enumerate() {
+                scan_dataset(item, original, &format!("{}[{}]>", location, i), findings);
+            }
+            continue;
+        }
+
+        let Some(value) = text_value(elem.vr(), elem.value().primitive()) else { continue };
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+
+        // Any value left in a PHI or warning attribute is reported, failing when a PHI
+        // attribute still holds an original value or any text the patient identity
+        let (severity, reason) = if is_phi_tag(tag) && original.is_original(tag, value) {
+            (Severity::Fail, "Original value kept")
+        } else if original
+            .identifiers
+            .as_ref()
+            .filter(|_| elem.vr() != VR::UI)
+            .is_some_and(|re| re.is_match(value))
+        {
+            (Severity::Fail, "Contains the patient name, ID or birth date")
+        } else if is_phi_tag(tag) || is_warning_tag(tag) {
+            (Severity::Warning, "Value left")
+        } else {
+            continue;
+        };
+        findings.push(Finding {
+            location,
+            severity,
+            reason: reason.to_string(),
+        });
+    }
+}
+
+/// Text of string elements, and of unknown ones (private attributes read implicitly)
+fn text_value(vr: VR, value: Option<&PrimitiveValue>) -> Option<String> {
+    let value = value?;
+    match vr {
+        VR::AE | VR::AS | VR::CS | VR::DA | VR::DS | VR::DT | VR::IS | VR::LO | VR::LT
+        | VR::PN | VR::SH | VR::ST | VR::TM | VR::UC | VR::UI | VR::UR | VR::UT => {
+            Some(value.to_multi_str().join("\\"))
+        }
+        VR::UN => Some(String::from_utf8_lossy(&value.to_bytes()).into_owned()),
+        _ => None,
+    }
+}
+
+/// Run-wide verification results
+#[derive(Debug, Default)]
+pub struct Verification {
+    reports: Mutex<Vec<FileReport>>,
+}
+
+impl Verification {
+    pub fn add(&self, report: FileReport, verbose: bool) -> Result<()> {
+        let status = if report.passed() { "PASS" } else { "FAIL" };
+        println!("{} {}", status, report.path.display());
+        for finding in &report.findings {
+            if finding.severity == Severity::Fail || verbose {
+                println!("  {:<7} {} {}", finding.severity.as_str(), finding.location, finding.reason);
+            }
+        }
+        self.reports.lock()?.push(report);
+        Ok(())
+    }
+
+    pub fn failed(&self) -> usize {
+        self.reports
+            .lock()
+            .map(|r| r.iter().filter(|r| !r.passed()).count())
+            .unwrap_or_default()
+    }
+
+    pub fn print_summary(&self) {
+        let total = self.reports.lock().map(|r| r.len()).unwrap_or_default();
+        let failed = self.failed();
+        println!("Verification: {} passed, {} failed", total - failed, failed);
+    }
+
+    /// One row per finding, files without any getting a single row
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let mut wrt = Writer::from_writer(File::create(path)?);
+        wrt.write_record(["file", "status", "location", "severity", "reason"])?;
+        for report in self.reports.lock()?.iter() {
+            let file = report.path.to_string_lossy();
+            let status = if report.passed() { "PASS" } else { "FAIL" };
+            if report.findings.is_empty() {
+                wrt.write_record([file.as_ref(), status, "", "", ""])?;
+            }
+            for f in &report.findings {
+                wrt.write_record([file.as_ref(), status, &f.location, f.severity.as_str(), &f.reason])?;
+            }
+        }
+        wrt.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::{DataElement, DataSetSequence, InMemElement, Value};
+
+    fn item(elements: Vec<InMemElement>) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter(elements)
+    }
+
+    fn string(tag: Tag, vr: VR, value: &str) -> InMemElement {
+        DataElement::new(tag, vr, PrimitiveValue::from(value))
+    }
+
+    fn nested(name: &str) -> InMemDicomObject {
+        let inner = item(vec![string(tags::PATIENT_NAME, VR::PN, name)]);
+        item(vec![
+            string(tags::PATIENT_NAME, VR::PN, ""),
+            DataElement::new(
+                tags::REQUEST_ATTRIBUTES_SEQUENCE,
+                VR::SQ,
+                Value::from(DataSetSequence::from(vec![inner])),
+            ),
+        ])
+    }
+
+    fn scan(data: &InMemDicomObject, original: &Original) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        scan_dataset(data, original, "", &mut findings);
+        findings
+    }
+
+    #[test]
+    fn nested_original_value_fails() {
+        let source = item(vec![
+            string(tags::PATIENT_NAME, VR::PN, "Doe^John"),
+            string(tags::PATIENT_ID, VR::LO, "AB12"),
+        ]);
+        let findings = scan(&nested("Doe^John"), &Original::new(&source));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location, "(0040,0275)[0]>(0010,0010)");
+        assert_eq!(findings[0].severity, Severity::Fail);
+    }
+
+    #[test]
+    fn nested_value_left_warns() {
+        // Not the name of the patient, but a PHI attribute still holding a value
+        let source = item(vec![string(tags::PATIENT_NAME, VR::PN, "Doe^John")]);
+        let findings = scan(&nested("Roe^Richard"), &Original::new(&source));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+
+        // Nested original values are known too
+        let findings = scan(&nested("Roe^Richard"), &Original::new(&nested("Roe^Richard")));
+        assert_eq!(findings[0].severity, Severity::Fail);
+    }
+
+    #[test]
+    fn identity_in_free_text_fails() {
+        let source = item(vec![
+            string(tags::PATIENT_NAME, VR::PN, "Doe^John"),
+            string(tags::PATIENT_BIRTH_DATE, VR::DA, "19800131"),
+        ]);
+        let original = Original::new(&source);
+        let output = item(vec![
+            string(tags::STUDY_DESCRIPTION, VR::LO, "CT of J. DOE born 31/01/1980"),
+            string(tags::SERIES_DESCRIPTION, VR::LO, "Chest"),
+            string(tags::IMAGE_COMMENTS, VR::LT, "Nothing"),
+        ]);
+        let findings = scan(&output, &original);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].severity, Severity::Fail);
+        assert_eq!(findings[1].severity, Severity::Warning);
+    }
+}
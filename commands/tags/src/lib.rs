@@ -1,8 +1,10 @@
 use pulsedcm_core::*;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
 use std::sync::Mutex;
 use std::{path::PathBuf};
 
-use csv::Writer;
+use csv::{Writer, WriterBuilder};
 use pulsedcm_core::{
     collect_dicom_files,
     open_file as open_dcm_file, 
@@ -28,89 +30,174 @@ pub fn run(
     jobs: usize,
     json: Option<PathBuf>,
     csv: Option<PathBuf>,
+    confirmation: Confirmation,
+    resume: bool,
+    summary: &RunSummary,
 ) -> Result<()> {
     // Setting up all components
     let to_display = csv.is_some() || json.is_some();
 
-    let export_data = Mutex::new(Vec::<SerializableDicomEntry>::new());
+    if resume && !to_display {
+        return Err(PulseError::new(
+            PulseErrorKind::IO(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Nothing to resume",
+            )),
+            "--resume needs the tags exported with --json or --csv",
+        ));
+    }
+    let json_export = json.map(|path| TagExport::create(ExportFormat::Json, path, confirmation, resume)).transpose()?;
+    let csv_export = csv.map(|path| TagExport::create(ExportFormat::Csv, path, confirmation, resume)).transpose()?;
+
+    // Only exported tags are kept track of, printed ones can't be resumed
+    let journal = json_export
+        .as_ref()
+        .or(csv_export.as_ref())
+        .map(|export| Journal::for_output(&export.path, "tags", resume))
+        .transpose()?;
+    let files = match &journal {
+        Some(journal) => journal.pending(files, summary)?,
+        None => files,
+    };
     let thread_pool = rayon::ThreadPoolBuilder::new()
         .num_threads(jobs)
         .build()?;
 
-    let _ = thread_pool.install(|| -> Result<()> {
-        let _ = files.par_iter().try_for_each(
-            |f: &PathBuf| -> Result<()> {
-                let mut out_string = String::new();
-                let path = f.as_os_str().to_str().unwrap_or("No path");
-                if !to_display {
-                    out_string.push_str(&format!("[{}]----\n", path));
-                }
-
-                // Skipping pixel data when specified or exporting to JSON/CSV
-                let obj = if !with_pixel_data || to_display {
-                    OpenFileOptions::new()
-                        .read_until(dicom_dictionary_std::tags::PIXEL_DATA)
-                        .open_file(path)?
-                } else {
-                    open_dcm_file(path)?
-                };
-
-                export_data.lock()?.extend(
-                    match &kind {
-                        TagFlags::All => all_tagging(path, &obj, to_display, &mut out_string),
-                        TagFlags::Short => short_tagging(path, &obj, to_display, &mut out_string),
-                        TagFlags::Specific(keys) => {
-                            specific_tagging(path, &keys, &obj, to_display, &mut out_string)
-                        }
-                }
-                );
-                println!("{}", out_string);
-                Ok(())
-            });
-        if let Some(json_path) = json.clone() {
-            let _ = write_tag_files("json", json_path, &export_data);
-        }
-
-        if let Some(csv_path) = csv.clone() {
-            let _ = write_tag_files("csv", csv_path, &export_data);
-        }
-        Ok(())
+    let exports: Vec<&TagExport> = json_export.iter().chain(csv_export.iter()).collect();
+    thread_pool.install(|| {
+        files.par_iter().for_each(|f: &PathBuf| {
+            summary.record(f, tag_file(f, &kind, with_pixel_data, to_display, &exports, journal.as_ref()));
+        });
     });
+    for export in exports {
+        export.saved();
+    }
     Ok(())
 }
 
-fn write_tag_files(
-    extension_type: &str,
-    mut arg_clone: PathBuf,
-    export_data: &Mutex<Vec<SerializableDicomEntry>>,
+fn tag_file(
+    f: &PathBuf,
+    kind: &TagFlags,
+    with_pixel_data: bool,
+    to_display: bool,
+    exports: &[&TagExport],
+    journal: Option<&Journal>,
 ) -> Result<()> {
-    if arg_clone.extension().is_none() {
-        arg_clone.set_extension(extension_type);
+    let mut out_string = String::new();
+    let path = f.as_os_str().to_str().unwrap_or("No path");
+    if !to_display {
+        out_string.push_str(&format!("[{}]----\n", path));
+    }
+
+    // Skipping pixel data when specified or exporting to JSON/CSV
+    let obj = if !with_pixel_data || to_display {
+        OpenFileOptions::new()
+            .read_until(dicom_dictionary_std::tags::PIXEL_DATA)
+            .open_file(path)?
+    } else {
+        open_dcm_file(path)?
+    };
+
+    let entries = match kind {
+        TagFlags::All => all_tagging(path, &obj, to_display, &mut out_string),
+        TagFlags::Short => short_tagging(path, &obj, to_display, &mut out_string),
+        TagFlags::Specific(keys) => {
+            specific_tagging(path, &keys, &obj, to_display, &mut out_string)
+        }
+    };
+    // Written as read, so that an interrupted export can be resumed
+    for export in exports {
+        export.write(&entries)?;
     }
-    if arg_clone.exists() {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)?
-            .as_secs();
-        let stem = arg_clone.file_stem().unwrap_or_default().to_string_lossy();
-        let parent = arg_clone.parent().unwrap_or_else(|| Path::new(""));
-        let new_name = format!("{}_{}.{}", stem, timestamp, extension_type);
-        arg_clone = parent.join(new_name);
+    if let Some(journal) = journal {
+        journal.complete(f)?;
+    }
+    println!("{}", out_string);
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ExportFormat {
+    Json,
+    Csv,
+}
+
+enum ExportWriter {
+    Csv(Box<Writer<File>>),
+    // One JSON object per entry, so that resumed runs can append to it
+    JsonLines(BufWriter<File>),
+}
+
+/// Export file filled as the files are processed
+struct TagExport {
+    path: PathBuf,
+    format: ExportFormat,
+    writer: Mutex<ExportWriter>,
+}
+
+impl TagExport {
+    /// An existing file is appended to when resuming, left aside for a new one otherwise,
+    /// or refused with `--no-clobber`
+    fn create(format: ExportFormat, mut path: PathBuf, confirmation: Confirmation, resume: bool) -> Result<Self> {
+        let extension_type = match format {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        };
+        if path.extension().is_none() {
+            path.set_extension(extension_type);
+        }
+        if !resume {
+            confirmation.check_destination(&path)?;
+        }
+        if path.exists() && !resume {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)?
+                .as_secs();
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let parent = path.parent().unwrap_or_else(|| Path::new(""));
+            let new_name = format!("{}_{}.{}", stem, timestamp, extension_type);
+            path = parent.join(new_name);
+            confirmation.check_destination(&path)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let writer = match format {
+            ExportFormat::Json => ExportWriter::JsonLines(BufWriter::new(file)),
+            ExportFormat::Csv => {
+                let has_headers = file.metadata()?.len() == 0;
+                ExportWriter::Csv(Box::new(WriterBuilder::new().has_headers(has_headers).from_writer(file)))
+            }
+        };
+        Ok(Self { path, format, writer: Mutex::new(writer) })
     }
-    let file = File::create(&arg_clone)?;
-    let mut wrt = Writer::from_writer(file);
-    for entry in export_data.lock()?.iter() {
-        wrt.serialize(entry)?;
+
+    fn write(&self, entries: &[SerializableDicomEntry]) -> Result<()> {
+        match &mut *self.writer.lock()? {
+            ExportWriter::Csv(wrt) => {
+                for entry in entries {
+                    wrt.serialize(entry)?;
+                }
+                wrt.flush()?;
+            }
+            ExportWriter::JsonLines(wrt) => {
+                for entry in entries {
+                    serde_json::to_writer(&mut *wrt, entry).map_err(std::io::Error::from)?;
+                    wrt.write_all(b"\n")?;
+                }
+                wrt.flush()?;
+            }
+        }
+        Ok(())
     }
 
-    match wrt.flush() {
-        Ok(_o) => {
-            println!(
-                "\x1b[1;32mSuccessfully\x1b[0m saved CSV as \x1b[1m{:?} \x1b[0m",
-                arg_clone
-            );
-            return Ok(());
-        },
-        Err(e) => Err(e.into()),
+    fn saved(&self) {
+        let format = match self.format {
+            ExportFormat::Json => "JSON",
+            ExportFormat::Csv => "CSV",
+        };
+        println!(
+            "\x1b[1;32mSuccessfully\x1b[0m saved {} as \x1b[1m{:?} \x1b[0m",
+            format, self.path
+        );
     }
 }
 
@@ -224,3 +311,42 @@ fn specific_tagging(
     }
     return output;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(filename: &str) -> SerializableDicomEntry {
+        SerializableDicomEntry {
+            filename: filename.to_string(),
+            name: "PatientName".to_string(),
+            tag: "(0010 0010)".to_string(),
+            vr: "PN".to_string(),
+            value: "Doe^John".to_string(),
+        }
+    }
+
+    #[test]
+    fn export_json_lines() {
+        let dir = std::env::temp_dir().join(format!("pulsedcm-tags-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tags");
+
+        let export = TagExport::create(ExportFormat::Json, path.clone(), Confirmation::Yes, false).unwrap();
+        assert_eq!(export.path, dir.join("tags.json"));
+        export.write(&[entry("a.dcm"), entry("b.dcm")]).unwrap();
+        drop(export);
+        // Appended to when resuming
+        let export = TagExport::create(ExportFormat::Json, path, Confirmation::Yes, true).unwrap();
+        export.write(&[entry("c.dcm")]).unwrap();
+        drop(export);
+
+        let content = std::fs::read_to_string(dir.join("tags.json")).unwrap();
+        let filenames: Vec<String> = content
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["filename"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(filenames, ["a.dcm", "b.dcm", "c.dcm"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
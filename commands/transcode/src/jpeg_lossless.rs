@@ -0,0 +1,257 @@
+use crate::pixels::NativePixels;
+
+/// Difference categories, from 0 to 16 bits
+const CATEGORIES: usize = 17;
+
+/// JPEG Lossless (ITU T.81 process 14, first-order prediction) codestream of a frame,
+/// every component sharing one Huffman table fitted to the frame
+pub fn encode_frame(pixels: &NativePixels, frame: usize) -> Vec<u8> {
+    let samples = pixels.frame_samples(frame);
+    let components = pixels.samples_per_pixel;
+    let precision = pixels.bits_stored.max(2);
+    let differences = differences(&samples, pixels.columns as usize, components, precision);
+
+    let mut frequencies = [0u64; CATEGORIES];
+    for difference in &differences {
+        frequencies[category(*difference)] += 1;
+    }
+    let (bits, values) = huffman_table(&frequencies);
+    let codes = huffman_codes(&bits, &values);
+
+    let mut out = vec![0xFF, 0xD8];
+
+    // Frame header, lossless Huffman
+    segment(&mut out, 0xC3, &{
+        let mut header = vec![precision as u8];
+        header.extend_from_slice(&(pixels.rows as u16).to_be_bytes());
+        header.extend_from_slice(&(pixels.columns as u16).to_be_bytes());
+        header.push(components as u8);
+        for component in 0..components {
+            header.extend_from_slice(&[component as u8 + 1, 0x11, 0]);
+        }
+        header
+    });
+
+    segment(&mut out, 0xC4, &{
+        let mut table = vec![0x00];
+        table.extend_from_slice(&bits);
+        table.extend_from_slice(&values);
+        table
+    });
+
+    // Scan header: predictor 1, no point transform
+    segment(&mut out, 0xDA, &{
+        let mut header = vec![components as u8];
+        for component in 0..components {
+            header.extend_from_slice(&[component as u8 + 1, 0x00]);
+        }
+        header.extend_from_slice(&[1, 0, 0]);
+        header
+    });
+
+    let mut writer = BitWriter::new(&mut out);
+    for difference in differences {
+        let category = category(difference);
+        let (code, length) = codes[category];
+        writer.write(code as u32, length);
+        // 16 bits differences have no additional bits
+        if category > 0 && category < 16 {
+            let bits = if difference < 0 { difference - 1 } else { difference };
+            writer.write(bits as u32 & ((1 << category) - 1), category as u8);
+        }
+    }
+    writer.flush();
+
+    out.extend_from_slice(&[0xFF, 0xD9]);
+    out
+}
+
+/// Difference of each sample to its prediction, modulo 2^16:
+/// the sample on the left, the one above at the start of a row, half the range first
+fn differences(samples: &[u32], columns: usize, components: usize, precision: u16) -> Vec<i32> {
+    let stride = columns * components;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(index, sample)| {
+            let (row, column) = (index / stride, index % stride / components);
+            let prediction = match (row, column) {
+                (0, 0) => 1 << (precision - 1),
+                (_, 0) => samples[index - stride],
+                _ => samples[index - components],
+            };
+            let difference = (*sample as i32 - prediction as i32) & 0xFFFF;
+            if difference >= 0x8000 { difference - 0x10000 } else { difference }
+        })
+        .collect()
+}
+
+fn category(difference: i32) -> usize {
+    match difference {
+        -0x8000 => 16,
+        _ => (32 - difference.unsigned_abs().leading_zeros()) as usize,
+    }
+}
+
+/// Code counts by length and symbols of an optimal table limited to 16 bits (ITU T.81 Annex K.2)
+fn huffman_table(frequencies: &[u64; CATEGORIES]) -> ([u8; 16], Vec<u8>) {
+    // A reserved symbol keeps any code from being all ones
+    let mut frequencies: Vec<u64> = frequencies.iter().copied().chain([1]).collect();
+    let symbols = frequencies.len();
+    let mut code_size = vec![0usize; symbols];
+    let mut others: Vec<Option<usize>> = vec![None; symbols];
+
+    loop {
+        // Least frequent symbols, the highest one on a tie
+        let least = |excluded: Option<usize>| {
+            (0..symbols)
+                .filter(|s| frequencies[*s] > 0 && Some(*s) != excluded)
+                .min_by_key(|s| (frequencies[*s], std::cmp::Reverse(*s)))
+        };
+        let Some(mut v1) = least(None) else { break };
+        let Some(mut v2) = least(Some(v1)) else { break };
+
+        frequencies[v1] += frequencies[v2];
+        frequencies[v2] = 0;
+
+        code_size[v1] += 1;
+        while let Some(next) = others[v1] {
+            v1 = next;
+            code_size[v1] += 1;
+        }
+        others[v1] = Some(v2);
+
+        code_size[v2] += 1;
+        while let Some(next) = others[v2] {
+            v2 = next;
+            code_size[v2] += 1;
+        }
+    }
+
+    let mut bits = [0usize; 33];
+    for size in code_size.iter().filter(|s| **s > 0) {
+        bits[*size] += 1;
+    }
+    // Lengths over 16 moved up the tree (Annex K.3)
+    let mut i = 32;
+    while i > 16 {
+        while bits[i] > 0 {
+            let mut j = i - 2;
+            while bits[j] == 0 {
+                j -= 1;
+            }
+            bits[i] -= 2;
+            bits[i - 1] += 1;
+            bits[j + 1] += 2;
+            bits[j] -= 1;
+        }
+        i -= 1;
+    }
+    // The reserved symbol is the longest code
+    while bits[i] == 0 {
+        i -= 1;
+    }
+    bits[i] -= 1;
+
+    let mut values = Vec::new();
+    for size in 1..=32 {
+        values.extend((0..CATEGORIES).filter(|s| code_size[*s] == size).map(|s| s as u8));
+    }
+    let mut counts = [0u8; 16];
+    for (count, bits) in counts.iter_mut().zip(&bits[1..=16]) {
+        *count = *bits as u8;
+    }
+    (counts, values)
+}
+
+/// Code and length of each category (ITU T.81 Annex C)
+fn huffman_codes(bits: &[u8; 16], values: &[u8]) -> [(u16, u8); CATEGORIES] {
+    let mut codes = [(0, 0); CATEGORIES];
+    let mut code = 0u16;
+    let mut values = values.iter();
+    for (length, count) in bits.iter().enumerate() {
+        for _ in 0..*count {
+            if let Some(value) = values.next() {
+                codes[*value as usize] = (code, length as u8 + 1);
+            }
+            code += 1;
+        }
+        code <<= 1;
+    }
+    codes
+}
+
+fn segment(out: &mut Vec<u8>, marker: u8, content: &[u8]) {
+    out.extend_from_slice(&[0xFF, marker]);
+    out.extend_from_slice(&(content.len() as u16 + 2).to_be_bytes());
+    out.extend_from_slice(content);
+}
+
+/// Entropy coded data, a 0 stuffed after each 0xFF byte
+struct BitWriter<'a> {
+    out: &'a mut Vec<u8>,
+    buffer: u64,
+    count: u8,
+}
+
+impl<'a> BitWriter<'a> {
+    fn new(out: &'a mut Vec<u8>) -> Self {
+        Self { out, buffer: 0, count: 0 }
+    }
+
+    fn write(&mut self, bits: u32, length: u8) {
+        self.buffer = (self.buffer << length) | bits as u64;
+        self.count += length;
+        while self.count >= 8 {
+            self.count -= 8;
+            let byte = (self.buffer >> self.count) as u8;
+            self.out.push(byte);
+            if byte == 0xFF {
+                self.out.push(0);
+            }
+        }
+    }
+
+    /// Last byte padded with ones
+    fn flush(&mut self) {
+        if self.count > 0 {
+            let padding = 8 - self.count;
+            self.write((1 << padding) - 1, padding);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixels::tests::native_object;
+    use pulsedcm_core::PixelDecoder;
+
+    #[test]
+    fn decoded_by_dicom_rs() {
+        for (samples_per_pixel, bits_allocated, bits_stored, signed) in
+            [(1, 8, 8, false), (1, 16, 16, false), (1, 16, 12, false), (1, 16, 16, true), (3, 8, 8, false), (3, 16, 16, false)]
+        {
+            let (mut obj, bytes) = native_object(samples_per_pixel, bits_allocated, bits_stored, signed, 3);
+            let pixels = NativePixels::from_object(&obj).unwrap();
+            let fragments = (0..pixels.frames).map(|frame| encode_frame(&pixels, frame)).collect();
+            crate::encapsulate(&mut obj, fragments);
+            obj.meta_mut().set_transfer_syntax(crate::syntax("1.2.840.10008.1.2.4.70").unwrap());
+
+            let decoded = obj.decode_pixel_data().unwrap();
+            assert!(decoded.data() == bytes, "{} sample(s) of {} bits", samples_per_pixel, bits_stored);
+        }
+    }
+
+    #[test]
+    fn huffman_codes_fit_16_bits() {
+        // Every category used, some far more than others
+        let mut frequencies = [1u64; CATEGORIES];
+        frequencies[0] = 1 << 40;
+        let (bits, values) = huffman_table(&frequencies);
+        assert_eq!(values.len(), CATEGORIES);
+        assert_eq!(bits.iter().map(|b| *b as usize).sum::<usize>(), CATEGORIES);
+        let codes = huffman_codes(&bits, &values);
+        assert!(codes.iter().all(|(code, length)| *length > 0 && *length <= 16 && *code as u32 != (1u32 << length) - 1));
+    }
+}
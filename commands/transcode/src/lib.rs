@@ -0,0 +1,437 @@
+use std::fs::{remove_file, rename, OpenOptions};
+use std::io::{self, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use jp2k::{Codec, Compression, DecodeParams, EncodeParams, SampleBuffer, Stream};
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, frame_codestreams, frame_fragments, mirror_path, open_file, create_dir_all, Confirmation, DataElement,
+    EncodeOptions, File, FileDicomObject, FileMetaTable, InMemDicomObject, Journal, Length,
+    PixelFragmentSequence, PrimitiveValue, PulseError, PulseErrorKind, Result, RunSummary, Tag, Transcode,
+    TransferSyntaxIndex, TransferSyntaxRegistry, VR,
+};
+use rayon::prelude::*;
+
+pub mod syntax;
+mod jpeg_lossless;
+mod pixels;
+mod rle;
+
+use crate::pixels::{unsupported, NativePixels};
+use crate::syntax::TargetSyntax;
+
+const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+const EXPLICIT_VR_BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
+
+/// Compression ratio of the lossy JPEG 2000 when none is given
+pub const DEFAULT_RATE: f32 = 10.;
+
+pub fn run(
+    files: Vec<PathBuf>,
+    input_root: PathBuf,
+    out: Option<PathBuf>,
+    target: TargetSyntax,
+    quality: Option<u8>,
+    rate: Option<f32>,
+    jobs: usize,
+    confirmation: Confirmation,
+    resume: bool,
+    summary: &RunSummary,
+) -> Result<()> {
+    check_options(target, quality, rate)?;
+    let in_place = out.is_none();
+    let output_root = out.unwrap_or_else(|| input_root.clone());
+    let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+
+    if in_place {
+        if !confirmation.overwrite_inputs(files.len(), summary)? {
+            return Ok(());
+        }
+    } else {
+        confirmation.prepare_output_dir(&output_root)?;
+    }
+
+    // Overwritten inputs get their journal out of the input tree
+    let journal = if in_place {
+        Journal::in_cache(&input_root, "transcode", resume)?
+    } else {
+        Journal::for_output(&output_root, "transcode", resume)?
+    };
+    let files = journal.pending(files, summary)?;
+    thread_pool.install(|| {
+        files.par_iter().for_each(|file| {
+            let outcome = destination(&input_root, &output_root, file, in_place, confirmation)
+                .and_then(|destination| transcode_file(file, &destination, target, quality, rate))
+                .and_then(|_| journal.complete(file));
+            summary.record(file, outcome);
+        });
+    });
+    Ok(())
+}
+
+/// `--quality` only applies to JPEG Baseline and `--rate` to the lossy JPEG 2000
+fn check_options(target: TargetSyntax, quality: Option<u8>, rate: Option<f32>) -> Result<()> {
+    let unused = if quality.is_some() && target != TargetSyntax::JpegBaseline {
+        "--quality only applies to --to jpeg"
+    } else if rate.is_some() && target != TargetSyntax::Jpeg2000 {
+        "--rate only applies to --to j2k"
+    } else {
+        return Ok(());
+    };
+    Err(PulseError::new(
+        PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidInput, "Option not applicable")),
+        unused,
+    ))
+}
+
+/// Input tree mirrored under the output directory, or the input itself in place
+fn destination(
+    input_root: &Path,
+    output_root: &Path,
+    input: &Path,
+    in_place: bool,
+    confirmation: Confirmation,
+) -> Result<PathBuf> {
+    if in_place {
+        return Ok(input.to_path_buf());
+    }
+    let destination = mirror_path(input_root, output_root, input)?;
+    confirmation.check_destination(&destination)?;
+    if let Some(parent) = destination.parent() {
+        create_dir_all(parent)?;
+    }
+    Ok(destination)
+}
+
+/// Convert a file to `target`, written to `destination`
+pub fn transcode_file(
+    input: &Path,
+    destination: &Path,
+    target: TargetSyntax,
+    quality: Option<u8>,
+    rate: Option<f32>,
+) -> Result<()> {
+    let mut obj = read_file(input)?;
+    // Already in the target syntax, a lossy one isn't compressed twice
+    if obj.meta().transfer_syntax() != target.uid() {
+        decompress(&mut obj)?;
+        compress(&mut obj, target, quality, rate)?;
+    }
+    write_file(&obj, target, destination)
+}
+
+/// Deflated files are inflated here, as dicom-rs doesn't read them
+fn read_file(path: &Path) -> Result<FileDicomObject<InMemDicomObject>> {
+    let mut file = BufReader::new(File::open(path)?);
+    let mut preamble = [0u8; 128];
+    file.read_exact(&mut preamble)?;
+    let meta = FileMetaTable::from_reader(&mut file)
+        .map_err(|e| PulseError::new(PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidData, e.to_string())), "Invalid file meta information"))?;
+    if meta.transfer_syntax() != TargetSyntax::DeflatedExplicitVrLittleEndian.uid() {
+        return Ok(open_file(path)?);
+    }
+
+    let dataset = InMemDicomObject::read_dataset_with_ts(DeflateDecoder::new(file), syntax(EXPLICIT_VR_LITTLE_ENDIAN)?)?;
+    let mut obj = dataset.with_exact_meta(meta);
+    obj.meta_mut().set_transfer_syntax(syntax(EXPLICIT_VR_LITTLE_ENDIAN)?);
+    Ok(obj)
+}
+
+/// Native pixel data, in Explicit VR Little Endian
+fn decompress(obj: &mut FileDicomObject<InMemDicomObject>) -> Result<()> {
+    let source = obj.meta().transfer_syntax().to_string();
+    if obj.get(tags::PIXEL_DATA).is_none() {
+        // Nothing to decode, only the syntax changes
+    } else if source == TargetSyntax::Jpeg2000Lossless.uid() || source == TargetSyntax::Jpeg2000.uid() {
+        decode_jpeg2000(obj)?;
+    } else if source == TargetSyntax::RleLossless.uid() {
+        decode_rle(obj)?;
+    } else if ![EXPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_BIG_ENDIAN, TargetSyntax::ImplicitVrLittleEndian.uid()]
+        .contains(&source.as_str())
+    {
+        obj.transcode(syntax(EXPLICIT_VR_LITTLE_ENDIAN)?)?;
+    }
+    // Only meaningful for encapsulated pixel data
+    for tag in [
+        tags::EXTENDED_OFFSET_TABLE,
+        tags::EXTENDED_OFFSET_TABLE_LENGTHS,
+        tags::ENCAPSULATED_PIXEL_DATA_VALUE_TOTAL_LENGTH,
+    ] {
+        obj.remove_element(tag);
+    }
+    obj.meta_mut().set_transfer_syntax(syntax(EXPLICIT_VR_LITTLE_ENDIAN)?);
+    Ok(())
+}
+
+/// JPEG 2000 isn't decoded by dicom-rs here, each frame goes through OpenJPEG
+fn decode_jpeg2000(obj: &mut FileDicomObject<InMemDicomObject>) -> Result<()> {
+    let bits_allocated = obj.get(tags::BITS_ALLOCATED).and_then(|e| e.to_int::<u16>().ok());
+    let mut data = Vec::new();
+    for codestream in frame_codestreams(obj)? {
+        let stream = Stream::from_bytes(&codestream)?;
+        let codec = Codec::create(jp2k::CODEC_FORMAT::OPJ_CODEC_J2K)?;
+        let samples = SampleBuffer::build(codec, stream, DecodeParams::default())?.samples;
+        match bits_allocated {
+            Some(8) => data.extend(samples.iter().map(|s| *s as u8)),
+            Some(16) => data.extend(samples.iter().flat_map(|s| (*s as u16).to_le_bytes())),
+            _ => return Err(unsupported(format!("{:?} bits allocated, expected 8 or 16", bits_allocated))),
+        }
+    }
+    put_native(obj, data);
+
+    // OpenJPEG reverses the component transform
+    let photometric = obj
+        .get(tags::PHOTOMETRIC_INTERPRETATION)
+        .and_then(|e| e.to_str().ok())
+        .map(|s| s.trim_end_matches(['\0', ' ']).to_string());
+    if matches!(photometric.as_deref(), Some("YBR_RCT" | "YBR_ICT")) {
+        obj.put(DataElement::new(tags::PHOTOMETRIC_INTERPRETATION, VR::CS, PrimitiveValue::from("RGB")));
+    }
+    Ok(())
+}
+
+fn decode_rle(obj: &mut FileDicomObject<InMemDicomObject>) -> Result<()> {
+    let int = |tag: Tag| obj.get(tag).and_then(|e| e.to_int::<usize>().ok());
+    let required = |tag: Tag, name: &str| {
+        int(tag).filter(|n| *n > 0).ok_or_else(|| unsupported(format!("Missing or invalid {}", name)))
+    };
+    let pixel_count = required(tags::ROWS, "Rows")? * required(tags::COLUMNS, "Columns")?;
+    let samples_per_pixel = int(tags::SAMPLES_PER_PIXEL).unwrap_or(1);
+    let bytes = match int(tags::BITS_ALLOCATED) {
+        Some(8) => 1,
+        Some(16) => 2,
+        other => return Err(unsupported(format!("{:?} bits allocated, expected 8 or 16", other))),
+    };
+
+    let mut data = Vec::new();
+    // Frames without an offset table are split at each RLE header
+    for fragment in frame_fragments(obj, rle::is_frame_start)? {
+        data.extend(rle::decode_frame(&fragment, pixel_count, samples_per_pixel, bytes)?);
+    }
+    put_native(obj, data);
+    Ok(())
+}
+
+/// Interleaved little endian samples put back as the Pixel Data
+fn put_native(obj: &mut FileDicomObject<InMemDicomObject>, data: Vec<u8>) {
+    let pixel_data = match obj.get(tags::BITS_ALLOCATED).and_then(|e| e.to_int::<u16>().ok()) {
+        Some(8) => DataElement::new(tags::PIXEL_DATA, VR::OB, PrimitiveValue::from(data)),
+        _ => DataElement::new(
+            tags::PIXEL_DATA,
+            VR::OW,
+            PrimitiveValue::U16(data.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect()),
+        ),
+    };
+    obj.put(pixel_data);
+    if obj.get(tags::PLANAR_CONFIGURATION).is_some() {
+        obj.put(DataElement::new(tags::PLANAR_CONFIGURATION, VR::US, PrimitiveValue::from(0u16)));
+    }
+}
+
+/// Encode the native pixel data to `target` and update the file meta
+fn compress(
+    obj: &mut FileDicomObject<InMemDicomObject>,
+    target: TargetSyntax,
+    quality: Option<u8>,
+    rate: Option<f32>,
+) -> Result<()> {
+    if target.is_native() || obj.get(tags::PIXEL_DATA).is_none() {
+        obj.meta_mut().set_transfer_syntax(syntax(target.uid())?);
+        return Ok(());
+    }
+
+    let pixels = NativePixels::from_object(obj)?;
+    let fragments = match target {
+        TargetSyntax::JpegBaseline => {
+            if pixels.bits_stored > 8 {
+                return Err(unsupported(format!(
+                    "JPEG Baseline holds 8-bit samples, not {}: use jpeg-lossless or j2k",
+                    pixels.bits_stored
+                )));
+            }
+            let mut options = EncodeOptions::new();
+            options.quality = quality;
+            // The ratio is added by dicom-rs
+            obj.transcode_with_options(syntax(target.uid())?, options)?;
+            mark_lossy(obj, None, "ISO_10918_1");
+            return Ok(());
+        }
+        TargetSyntax::RleLossless => (0..pixels.frames).map(|f| rle::encode_frame(&pixels, f)).collect(),
+        TargetSyntax::JpegLossless => (0..pixels.frames).map(|f| jpeg_lossless::encode_frame(&pixels, f)).collect(),
+        TargetSyntax::Jpeg2000Lossless => encode_jpeg2000(&pixels, Compression::Lossless)?,
+        _ => encode_jpeg2000(&pixels, Compression::Rate(rate.unwrap_or(DEFAULT_RATE)))?,
+    };
+
+    let compressed = encapsulate(obj, fragments);
+    if target.is_lossy() {
+        let ratio = (pixels.frame_size() * pixels.frames) as f64 / compressed.max(1) as f64;
+        mark_lossy(obj, Some(ratio), "ISO_15444_1");
+    }
+    if pixels.samples_per_pixel > 1 {
+        obj.put(DataElement::new(tags::PLANAR_CONFIGURATION, VR::US, PrimitiveValue::from(0u16)));
+    }
+    obj.meta_mut().set_transfer_syntax(syntax(target.uid())?);
+    Ok(())
+}
+
+fn encode_jpeg2000(pixels: &NativePixels, compression: Compression) -> Result<Vec<Vec<u8>>> {
+    (0..pixels.frames)
+        .map(|frame| {
+            let samples = SampleBuffer {
+                samples: pixels.frame_values(frame),
+                width: pixels.columns,
+                height: pixels.rows,
+                num_bands: pixels.samples_per_pixel,
+                precision: pixels.bits_stored as u32,
+                signed: pixels.signed,
+            };
+            Ok(samples.encode(EncodeParams::default().with_compression(compression))?)
+        })
+        .collect()
+}
+
+/// One fragment per frame, with a Basic Offset Table. Returns the size of the fragments
+fn encapsulate(obj: &mut FileDicomObject<InMemDicomObject>, mut fragments: Vec<Vec<u8>>) -> usize {
+    let mut offsets = Vec::with_capacity(fragments.len());
+    let mut offset = 0;
+    for fragment in fragments.iter_mut() {
+        // Item values have an even length
+        if fragment.len() % 2 == 1 {
+            fragment.push(0);
+        }
+        offsets.push(offset);
+        offset += 8 + fragment.len() as u32;
+    }
+    let size = fragments.iter().map(Vec::len).sum();
+    obj.put(DataElement::new_with_len(
+        tags::PIXEL_DATA,
+        VR::OB,
+        Length::UNDEFINED,
+        PixelFragmentSequence::new(offsets, fragments),
+    ));
+    size
+}
+
+/// Lossy Image Compression set, the ratio and method appended to the previous ones
+fn mark_lossy(obj: &mut FileDicomObject<InMemDicomObject>, ratio: Option<f64>, method: &str) {
+    let values = |obj: &FileDicomObject<InMemDicomObject>, tag: Tag| -> Vec<String> {
+        obj.get(tag)
+            .and_then(|e| e.to_multi_str().ok())
+            .map(|v| v.iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default()
+    };
+    obj.put(DataElement::new(tags::LOSSY_IMAGE_COMPRESSION, VR::CS, PrimitiveValue::from("01")));
+    if let Some(ratio) = ratio {
+        let mut ratios = values(obj, tags::LOSSY_IMAGE_COMPRESSION_RATIO);
+        ratios.push(format!("{:.2}", ratio));
+        obj.put(DataElement::new(tags::LOSSY_IMAGE_COMPRESSION_RATIO, VR::DS, PrimitiveValue::Strs(ratios.into())));
+    }
+    let mut methods = values(obj, tags::LOSSY_IMAGE_COMPRESSION_METHOD);
+    methods.push(method.to_string());
+    obj.put(DataElement::new(tags::LOSSY_IMAGE_COMPRESSION_METHOD, VR::CS, PrimitiveValue::Strs(methods.into())));
+}
+
+/// Serialized first then written through a temporary file renamed over the destination,
+/// a failed write leaving the previous file untouched
+fn write_file(obj: &FileDicomObject<InMemDicomObject>, target: TargetSyntax, destination: &Path) -> Result<()> {
+    let mut bytes = Vec::new();
+    if target == TargetSyntax::DeflatedExplicitVrLittleEndian {
+        bytes.extend_from_slice(&[0u8; 128]);
+        bytes.extend_from_slice(b"DICM");
+        obj.write_meta(&mut bytes)?;
+        let mut encoder = DeflateEncoder::new(&mut bytes, flate2::Compression::default());
+        obj.write_dataset_with_ts(&mut encoder, syntax(EXPLICIT_VR_LITTLE_ENDIAN)?)?;
+        encoder.finish()?;
+    } else {
+        obj.write_all(&mut bytes)?;
+    }
+
+    let dir = destination.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let name = destination.file_name().unwrap_or_default().to_string_lossy();
+    let temp = dir.join(format!(".{}.{}.tmp", name, std::process::id()));
+    let written = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp)
+        .and_then(|mut file| {
+            file.write_all(&bytes)?;
+            file.sync_all()
+        })
+        .and_then(|_| rename(&temp, destination));
+    if let Err(e) = written {
+        let _ = remove_file(&temp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+fn syntax(uid: &str) -> Result<&'static pulsedcm_core::dicom_transfer_syntax_registry::TransferSyntax> {
+    TransferSyntaxRegistry
+        .get(uid)
+        .ok_or_else(|| unsupported(format!("Unknown transfer syntax {}", uid)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_the_input_tree() {
+        let root = std::env::temp_dir().join(format!("pulsedcm-transcode-{}", std::process::id()));
+        let (input, output) = (root.join("in"), root.join("out"));
+        std::fs::create_dir_all(&input).unwrap();
+
+        let mirrored = destination(&input, &output, &input.join("a/b.dcm"), false, Confirmation::Yes).unwrap();
+        assert_eq!(mirrored, output.join("a/b.dcm"));
+        assert!(destination(&input, &output, &root.join("c.dcm"), false, Confirmation::Yes).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// RLE object of the frames of `obj`, each frame split in two fragments without offset table
+    fn split_rle(obj: &mut FileDicomObject<InMemDicomObject>) {
+        let pixels = NativePixels::from_object(obj).unwrap();
+        let fragments: Vec<Vec<u8>> = (0..pixels.frames)
+            .flat_map(|frame| {
+                let fragment = rle::encode_frame(&pixels, frame);
+                let (first, rest) = fragment.split_at(100);
+                [first.to_vec(), rest.to_vec()]
+            })
+            .collect();
+        obj.put(DataElement::new_with_len(
+            tags::PIXEL_DATA,
+            VR::OB,
+            Length::UNDEFINED,
+            PixelFragmentSequence::new(vec![], fragments),
+        ));
+        obj.meta_mut().set_transfer_syntax(syntax(TargetSyntax::RleLossless.uid()).unwrap());
+    }
+
+    #[test]
+    fn reject_options_of_other_syntaxes() {
+        assert!(check_options(TargetSyntax::JpegBaseline, Some(90), None).is_ok());
+        assert!(check_options(TargetSyntax::Jpeg2000, None, Some(20.)).is_ok());
+        assert!(check_options(TargetSyntax::RleLossless, None, None).is_ok());
+        assert!(check_options(TargetSyntax::Jpeg2000, Some(90), None).is_err());
+        assert!(check_options(TargetSyntax::Jpeg2000Lossless, None, Some(20.)).is_err());
+        assert!(check_options(TargetSyntax::JpegBaseline, Some(90), Some(20.)).is_err());
+    }
+
+    #[test]
+    fn decode_split_rle_frames() {
+        for (samples_per_pixel, bits_allocated) in [(1, 8), (1, 16), (3, 8), (3, 16)] {
+            let (mut obj, bytes) = crate::pixels::tests::native_object(samples_per_pixel, bits_allocated, bits_allocated, false, 3);
+            split_rle(&mut obj);
+            decompress(&mut obj).unwrap();
+            assert_eq!(NativePixels::from_object(&obj).unwrap().frame_bytes(2), &bytes[bytes.len() / 3 * 2..]);
+        }
+    }
+
+    #[test]
+    fn reject_rle_without_dimensions() {
+        let (mut obj, _) = crate::pixels::tests::native_object(1, 16, 16, false, 2);
+        split_rle(&mut obj);
+        obj.remove_element(tags::COLUMNS);
+        assert!(decompress(&mut obj).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, InMemDicomObject, PrimitiveValue, PulseError, PulseErrorKind, Result, Tag,
+};
+
+/// Native pixel data of an object, each frame made of interleaved little endian samples
+#[derive(Debug)]
+pub struct NativePixels {
+    pub rows: u32,
+    pub columns: u32,
+    pub samples_per_pixel: usize,
+    pub bits_allocated: u16,
+    pub bits_stored: u16,
+    pub signed: bool,
+    pub frames: usize,
+    data: Vec<u8>,
+}
+
+impl NativePixels {
+    pub fn from_object(obj: &InMemDicomObject) -> Result<Self> {
+        let int = |tag: Tag| obj.get(tag).and_then(|e| e.to_int::<u32>().ok());
+        let required = |tag: Tag, name: &str| {
+            int(tag).ok_or_else(|| unsupported(format!("Missing or invalid {}", name)))
+        };
+
+        let rows = required(tags::ROWS, "Rows")?;
+        let columns = required(tags::COLUMNS, "Columns")?;
+        let bits_allocated = required(tags::BITS_ALLOCATED, "Bits Allocated")? as u16;
+        if bits_allocated != 8 && bits_allocated != 16 {
+            return Err(unsupported(format!("{} bits allocated, expected 8 or 16", bits_allocated)));
+        }
+        let bits_stored = int(tags::BITS_STORED).map_or(bits_allocated, |b| b as u16).clamp(1, bits_allocated);
+        let samples_per_pixel = int(tags::SAMPLES_PER_PIXEL).unwrap_or(1) as usize;
+        let frames = int(tags::NUMBER_OF_FRAMES).filter(|n| *n > 0).unwrap_or(1) as usize;
+
+        let element = obj
+            .get(tags::PIXEL_DATA)
+            .ok_or_else(|| unsupported("No Pixel Data in the file".to_string()))?;
+        let mut data = match element.value().primitive() {
+            Some(PrimitiveValue::U16(values)) => values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+            Some(other) => other.to_bytes().into_owned(),
+            None => return Err(unsupported("Pixel Data isn't native".to_string())),
+        };
+
+        let pixels = Self {
+            rows,
+            columns,
+            samples_per_pixel,
+            bits_allocated,
+            bits_stored,
+            signed: int(tags::PIXEL_REPRESENTATION) == Some(1),
+            frames,
+            data: Vec::new(),
+        };
+        let size = pixels.frame_size() * frames;
+        if data.len() < size {
+            return Err(unsupported(format!(
+                "Pixel Data holds {} bytes, expected {} for {} frame(s)",
+                data.len(),
+                size,
+                frames
+            )));
+        }
+        data.truncate(size);
+
+        // Colour planes one after the other, interleaved here
+        if samples_per_pixel > 1 && int(tags::PLANAR_CONFIGURATION) == Some(1) {
+            data = data
+                .chunks_exact(pixels.frame_size())
+                .flat_map(|frame| pixels.interleave(frame))
+                .collect();
+        }
+        Ok(Self { data, ..pixels })
+    }
+
+    pub fn bytes_per_sample(&self) -> usize {
+        self.bits_allocated as usize / 8
+    }
+
+    pub fn pixel_count(&self) -> usize {
+        self.rows as usize * self.columns as usize
+    }
+
+    /// Bytes of a frame
+    pub fn frame_size(&self) -> usize {
+        self.pixel_count() * self.samples_per_pixel * self.bytes_per_sample()
+    }
+
+    pub fn frame_bytes(&self, frame: usize) -> &[u8] {
+        &self.data[frame * self.frame_size()..(frame + 1) * self.frame_size()]
+    }
+
+    /// Stored bits of each sample, as unsigned
+    pub fn frame_samples(&self, frame: usize) -> Vec<u32> {
+        let mask = (1u32 << self.bits_stored) - 1;
+        let bytes = self.frame_bytes(frame);
+        match self.bytes_per_sample() {
+            1 => bytes.iter().map(|b| *b as u32 & mask).collect(),
+            _ => bytes
+                .chunks_exact(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]) as u32 & mask)
+                .collect(),
+        }
+    }
+
+    /// Value of each sample, sign extended for a signed pixel representation
+    pub fn frame_values(&self, frame: usize) -> Vec<i32> {
+        let shift = 32 - self.bits_stored as u32;
+        self.frame_samples(frame)
+            .into_iter()
+            .map(|s| if self.signed { ((s << shift) as i32) >> shift } else { s as i32 })
+            .collect()
+    }
+
+    fn interleave(&self, planar: &[u8]) -> Vec<u8> {
+        let bytes = self.bytes_per_sample();
+        let plane = self.pixel_count() * bytes;
+        let mut interleaved = Vec::with_capacity(planar.len());
+        for pixel in 0..self.pixel_count() {
+            for sample in 0..self.samples_per_pixel {
+                let start = sample * plane + pixel * bytes;
+                interleaved.extend_from_slice(&planar[start..start + bytes]);
+            }
+        }
+        interleaved
+    }
+}
+
+pub fn unsupported(details: String) -> PulseError {
+    PulseError::new(PulseErrorKind::UnsupportedPixelData, details)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use pulsedcm_core::{DataElement, FileDicomObject, FileMetaTableBuilder, VR};
+
+    const ROWS: u16 = 7;
+    const COLUMNS: u16 = 9;
+
+    /// Stored values over the whole range of `bits_stored`, the extremes first,
+    /// the unused high bits left at 0
+    pub fn samples(count: usize, bits_stored: u16) -> Vec<u32> {
+        let mask = (1u32 << bits_stored) - 1;
+        (0..count as u32)
+            .map(|i| match i {
+                0 => 0,
+                1 => mask,
+                _ => i.wrapping_mul(2654435761) >> 7 & mask,
+            })
+            .collect()
+    }
+
+    /// Native object of `ROWS` x `COLUMNS` pixels in Explicit VR Little Endian,
+    /// with its Pixel Data as little endian bytes
+    pub fn native_object(
+        samples_per_pixel: u16,
+        bits_allocated: u16,
+        bits_stored: u16,
+        signed: bool,
+        frames: u32,
+    ) -> (FileDicomObject<InMemDicomObject>, Vec<u8>) {
+        let count = ROWS as usize * COLUMNS as usize * samples_per_pixel as usize * frames as usize;
+        let values = samples(count, bits_stored);
+        let (pixel_data, bytes) = match bits_allocated {
+            8 => {
+                let bytes: Vec<u8> = values.iter().map(|v| *v as u8).collect();
+                (DataElement::new(tags::PIXEL_DATA, VR::OB, PrimitiveValue::from(bytes.clone())), bytes)
+            }
+            _ => {
+                let words: Vec<u16> = values.iter().map(|v| *v as u16).collect();
+                let bytes = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+                (DataElement::new(tags::PIXEL_DATA, VR::OW, PrimitiveValue::U16(words.into())), bytes)
+            }
+        };
+        let photometric = if samples_per_pixel == 3 { "RGB" } else { "MONOCHROME2" };
+        let mut obj = InMemDicomObject::from_element_iter([
+            DataElement::new(tags::SOP_CLASS_UID, VR::UI, PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.7")),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, PrimitiveValue::from("1.2.3.4")),
+            DataElement::new(tags::SAMPLES_PER_PIXEL, VR::US, PrimitiveValue::from(samples_per_pixel)),
+            DataElement::new(tags::PHOTOMETRIC_INTERPRETATION, VR::CS, PrimitiveValue::from(photometric)),
+            DataElement::new(tags::NUMBER_OF_FRAMES, VR::IS, PrimitiveValue::from(frames.to_string())),
+            DataElement::new(tags::ROWS, VR::US, PrimitiveValue::from(ROWS)),
+            DataElement::new(tags::COLUMNS, VR::US, PrimitiveValue::from(COLUMNS)),
+            DataElement::new(tags::BITS_ALLOCATED, VR::US, PrimitiveValue::from(bits_allocated)),
+            DataElement::new(tags::BITS_STORED, VR::US, PrimitiveValue::from(bits_stored)),
+            DataElement::new(tags::HIGH_BIT, VR::US, PrimitiveValue::from(bits_stored - 1)),
+            DataElement::new(tags::PIXEL_REPRESENTATION, VR::US, PrimitiveValue::from(signed as u16)),
+            pixel_data,
+        ]);
+        if samples_per_pixel > 1 {
+            obj.put(DataElement::new(tags::PLANAR_CONFIGURATION, VR::US, PrimitiveValue::from(0u16)));
+        }
+        let meta = FileMetaTableBuilder::new()
+            .transfer_syntax("1.2.840.10008.1.2.1")
+            .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.7")
+            .media_storage_sop_instance_uid("1.2.3.4");
+        (obj.with_meta(meta).unwrap(), bytes)
+    }
+
+    #[test]
+    fn split_frames() {
+        for (samples_per_pixel, bits_allocated, bits_stored) in [(1, 8, 8), (1, 16, 12), (3, 8, 8), (3, 16, 16)] {
+            let (obj, bytes) = native_object(samples_per_pixel, bits_allocated, bits_stored, false, 3);
+            let pixels = NativePixels::from_object(&obj).unwrap();
+            assert_eq!(pixels.frames, 3);
+            let frame_size = ROWS as usize * COLUMNS as usize * samples_per_pixel as usize * bits_allocated as usize / 8;
+            assert_eq!(pixels.frame_size(), frame_size);
+            for frame in 0..3 {
+                assert_eq!(pixels.frame_bytes(frame), &bytes[frame * frame_size..(frame + 1) * frame_size]);
+            }
+        }
+    }
+
+    #[test]
+    fn sign_extend_values() {
+        let (mut obj, _) = native_object(1, 16, 12, true, 1);
+        obj.put(DataElement::new(tags::PIXEL_DATA, VR::OW, PrimitiveValue::U16([0x0800, 0x0FFF, 0xF7FF, 0x07FF].repeat(16).into())));
+        let pixels = NativePixels::from_object(&obj).unwrap();
+        assert_eq!(pixels.frame_samples(0)[..4], [0x800, 0xFFF, 0x7FF, 0x7FF]);
+        assert_eq!(pixels.frame_values(0)[..4], [-2048, -1, 2047, 2047]);
+    }
+
+    #[test]
+    fn interleave_planar_frames() {
+        let (mut obj, _) = native_object(3, 8, 8, false, 2);
+        let plane = ROWS as usize * COLUMNS as usize;
+        // Frames of red, green then blue planes, each sample its plane number
+        let planar: Vec<u8> = (0..2).flat_map(|frame| (0..3).flat_map(move |p| vec![frame * 3 + p; plane])).collect();
+        obj.put(DataElement::new(tags::PIXEL_DATA, VR::OB, PrimitiveValue::from(planar)));
+        obj.put(DataElement::new(tags::PLANAR_CONFIGURATION, VR::US, PrimitiveValue::from(1u16)));
+        let pixels = NativePixels::from_object(&obj).unwrap();
+        assert_eq!(pixels.frame_bytes(0)[..6], [0, 1, 2, 0, 1, 2]);
+        assert_eq!(pixels.frame_bytes(1)[..6], [3, 4, 5, 3, 4, 5]);
+    }
+
+    #[test]
+    fn reject_invalid_pixel_data() {
+        let (mut obj, _) = native_object(1, 16, 16, false, 2);
+        obj.put(DataElement::new(tags::NUMBER_OF_FRAMES, VR::IS, PrimitiveValue::from("3")));
+        assert!(NativePixels::from_object(&obj).is_err());
+        let (mut obj, _) = native_object(1, 16, 16, false, 1);
+        obj.remove_element(tags::ROWS);
+        assert!(NativePixels::from_object(&obj).is_err());
+        let (mut obj, _) = native_object(1, 16, 16, false, 1);
+        obj.put(DataElement::new(tags::BITS_ALLOCATED, VR::US, PrimitiveValue::from(32u16)));
+        assert!(NativePixels::from_object(&obj).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use pulsedcm_core::Result;
+
+use crate::pixels::{unsupported, NativePixels};
+
+/// Bytes of the header giving the number of segments and their offsets
+const HEADER_SIZE: usize = 64;
+
+/// RLE Lossless fragment of a frame (PS3.5 Annex G): one PackBits segment per sample
+/// and byte, most significant byte first, each row encoded on its own
+pub fn encode_frame(pixels: &NativePixels, frame: usize) -> Vec<u8> {
+    let data = pixels.frame_bytes(frame);
+    let bytes = pixels.bytes_per_sample();
+    let stride = pixels.samples_per_pixel * bytes;
+    let columns = pixels.columns as usize;
+
+    let mut segments = Vec::with_capacity(pixels.samples_per_pixel * bytes);
+    for sample in 0..pixels.samples_per_pixel {
+        for byte in (0..bytes).rev() {
+            let plane: Vec<u8> = data.iter().skip(sample * bytes + byte).step_by(stride).copied().collect();
+            let mut segment = Vec::new();
+            for row in plane.chunks(columns) {
+                pack_bits(row, &mut segment);
+            }
+            if segment.len() % 2 == 1 {
+                segment.push(0);
+            }
+            segments.push(segment);
+        }
+    }
+
+    let mut fragment = Vec::with_capacity(HEADER_SIZE + segments.iter().map(Vec::len).sum::<usize>());
+    fragment.extend_from_slice(&(segments.len() as u32).to_le_bytes());
+    let mut offset = HEADER_SIZE;
+    for index in 0..15 {
+        let value = match segments.get(index) {
+            Some(segment) => {
+                let start = offset;
+                offset += segment.len();
+                start as u32
+            }
+            None => 0,
+        };
+        fragment.extend_from_slice(&value.to_le_bytes());
+    }
+    for segment in segments {
+        fragment.extend_from_slice(&segment);
+    }
+    fragment
+}
+
+/// Runs of 2 to 128 equal bytes as replicate runs, the other bytes as literal runs
+fn pack_bits(row: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < row.len() {
+        let run = row[i..].iter().take(128).take_while(|b| **b == row[i]).count();
+        if run > 1 {
+            out.push((257 - run) as u8);
+            out.push(row[i]);
+            i += run;
+            continue;
+        }
+        // Literal up to the next run or 128 bytes
+        let start = i;
+        while i < row.len() && i - start < 128 && !(i + 1 < row.len() && row[i] == row[i + 1]) {
+            i += 1;
+        }
+        out.push((i - start - 1) as u8);
+        out.extend_from_slice(&row[start..i]);
+    }
+}
+
+/// Whether a fragment starts with an RLE header: 1 to 15 segments, the first one right
+/// after the header, the next ones in order and the unused offsets left at 0
+pub fn is_frame_start(fragment: &[u8]) -> bool {
+    let Some(header) = fragment.get(..HEADER_SIZE) else {
+        return false;
+    };
+    let values: Vec<usize> = header
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+        .collect();
+    let count = values[0];
+    if !(1..=15).contains(&count) || values[1] != HEADER_SIZE {
+        return false;
+    }
+    let (offsets, unused) = values[1..].split_at(count);
+    offsets.windows(2).all(|pair| pair[0] <= pair[1]) && unused.iter().all(|offset| *offset == 0)
+}
+
+/// Interleaved little endian samples of an RLE Lossless fragment.
+/// dicom-rs misplaces the segments of 8-bit greyscale and 16-bit colour frames, so they're decoded here
+pub fn decode_frame(fragment: &[u8], pixel_count: usize, samples_per_pixel: usize, bytes: usize) -> Result<Vec<u8>> {
+    let invalid = |details: &str| unsupported(format!("Invalid RLE fragment: {}", details));
+    let header = fragment.get(..HEADER_SIZE).ok_or_else(|| invalid("truncated header"))?;
+    let read = |index: usize| {
+        let offset = index * 4;
+        u32::from_le_bytes([header[offset], header[offset + 1], header[offset + 2], header[offset + 3]]) as usize
+    };
+    let count = samples_per_pixel * bytes;
+    if read(0) != count {
+        return Err(invalid(&format!("{} segments, expected {}", read(0), count)));
+    }
+
+    let stride = samples_per_pixel * bytes;
+    let mut data = vec![0u8; pixel_count * stride];
+    for segment in 0..count {
+        let start = read(segment + 1);
+        let end = if segment + 1 < count { read(segment + 2) } else { fragment.len() };
+        let encoded = fragment.get(start..end).ok_or_else(|| invalid("segment out of the fragment"))?;
+        let decoded = unpack_bits(encoded, pixel_count);
+        if decoded.len() < pixel_count {
+            return Err(invalid("segment shorter than the frame"));
+        }
+        // Most significant byte first
+        let (sample, byte) = (segment / bytes, bytes - 1 - segment % bytes);
+        for (pixel, value) in decoded.into_iter().enumerate() {
+            data[pixel * stride + sample * bytes + byte] = value;
+        }
+    }
+    Ok(data)
+}
+
+/// Up to `size` bytes of a PackBits segment
+fn unpack_bits(encoded: &[u8], size: usize) -> Vec<u8> {
+    let mut decoded = Vec::with_capacity(size);
+    let mut i = 0;
+    while i < encoded.len() && decoded.len() < size {
+        let header = encoded[i] as i8;
+        i += 1;
+        match header {
+            0..=127 => {
+                let end = (i + header as usize + 1).min(encoded.len());
+                decoded.extend_from_slice(&encoded[i..end]);
+                i = end;
+            }
+            -127..=-1 => {
+                if let Some(value) = encoded.get(i) {
+                    decoded.extend(std::iter::repeat_n(*value, (1 - header as isize) as usize));
+                }
+                i += 1;
+            }
+            // No operation
+            -128 => {}
+        }
+    }
+    decoded.truncate(size);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixels::tests::native_object;
+
+    #[test]
+    fn round_trip() {
+        for (samples_per_pixel, bits_allocated, bits_stored, signed) in
+            [(1, 8, 8, false), (1, 16, 16, false), (1, 16, 12, true), (1, 16, 16, true), (3, 8, 8, false), (3, 16, 16, false)]
+        {
+            let (obj, bytes) = native_object(samples_per_pixel, bits_allocated, bits_stored, signed, 3);
+            let pixels = NativePixels::from_object(&obj).unwrap();
+            let decoded: Vec<u8> = (0..pixels.frames)
+                .flat_map(|frame| {
+                    let fragment = encode_frame(&pixels, frame);
+                    assert!(is_frame_start(&fragment));
+                    decode_frame(&fragment, pixels.pixel_count(), pixels.samples_per_pixel, pixels.bytes_per_sample()).unwrap()
+                })
+                .collect();
+            assert!(decoded == bytes, "{} sample(s) of {} bits", samples_per_pixel, bits_allocated);
+        }
+    }
+
+    #[test]
+    fn pack_long_runs() {
+        let row: Vec<u8> = [vec![7; 300], (0..=255).collect(), vec![1, 2, 2, 3]].concat();
+        let mut packed = Vec::new();
+        pack_bits(&row, &mut packed);
+        assert!(packed.len() < row.len());
+        assert_eq!(unpack_bits(&packed, row.len()), row);
+    }
+
+    #[test]
+    fn find_frame_starts() {
+        let (obj, _) = native_object(3, 16, 16, false, 1);
+        let fragment = encode_frame(&NativePixels::from_object(&obj).unwrap(), 0);
+        assert!(is_frame_start(&fragment));
+        // Rest of a frame split in two fragments
+        assert!(!is_frame_start(&fragment[64..]));
+        let mut header = fragment[..64].to_vec();
+        header[0] = 16;
+        assert!(!is_frame_start(&header));
+    }
+
+    #[test]
+    fn reject_invalid_fragments() {
+        let (obj, _) = native_object(1, 16, 16, false, 1);
+        let pixels = NativePixels::from_object(&obj).unwrap();
+        let fragment = encode_frame(&pixels, 0);
+        let pixel_count = pixels.pixel_count();
+        assert!(decode_frame(&fragment[..32], pixel_count, 1, 2).is_err());
+        assert!(decode_frame(&fragment, pixel_count, 3, 2).is_err());
+        assert!(decode_frame(&fragment, pixel_count + 1, 1, 2).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+/// Transfer syntax a file is converted to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TargetSyntax {
+    ExplicitVrLittleEndian,
+    ImplicitVrLittleEndian,
+    DeflatedExplicitVrLittleEndian,
+    RleLossless,
+    /// Lossy, 8-bit samples only
+    JpegBaseline,
+    /// Process 14, first-order prediction
+    JpegLossless,
+    Jpeg2000Lossless,
+    /// Lossy, at the rate given to the run
+    Jpeg2000,
+}
+
+impl FromStr for TargetSyntax {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "explicit" => Ok(Self::ExplicitVrLittleEndian),
+            "implicit" => Ok(Self::ImplicitVrLittleEndian),
+            "deflated" => Ok(Self::DeflatedExplicitVrLittleEndian),
+            "rle" => Ok(Self::RleLossless),
+            "jpeg" => Ok(Self::JpegBaseline),
+            "jpeg-lossless" => Ok(Self::JpegLossless),
+            "j2k-lossless" => Ok(Self::Jpeg2000Lossless),
+            "j2k" => Ok(Self::Jpeg2000),
+            other => TargetSyntax::from_uid(other).ok_or_else(|| {
+                format!(
+                    "Invalid transfer syntax '{}', expected explicit, implicit, deflated, rle, jpeg, jpeg-lossless, j2k-lossless, j2k or one of their UIDs",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+impl TargetSyntax {
+    pub fn uid(&self) -> &'static str {
+        match self {
+            Self::ExplicitVrLittleEndian => "1.2.840.10008.1.2.1",
+            Self::ImplicitVrLittleEndian => "1.2.840.10008.1.2",
+            Self::DeflatedExplicitVrLittleEndian => "1.2.840.10008.1.2.1.99",
+            Self::RleLossless => "1.2.840.10008.1.2.5",
+            Self::JpegBaseline => "1.2.840.10008.1.2.4.50",
+            Self::JpegLossless => "1.2.840.10008.1.2.4.70",
+            Self::Jpeg2000Lossless => "1.2.840.10008.1.2.4.90",
+            Self::Jpeg2000 => "1.2.840.10008.1.2.4.91",
+        }
+    }
+
+    fn from_uid(uid: &str) -> Option<Self> {
+        [
+            Self::ExplicitVrLittleEndian,
+            Self::ImplicitVrLittleEndian,
+            Self::DeflatedExplicitVrLittleEndian,
+            Self::RleLossless,
+            Self::JpegBaseline,
+            Self::JpegLossless,
+            Self::Jpeg2000Lossless,
+            Self::Jpeg2000,
+        ]
+        .into_iter()
+        .find(|syntax| syntax.uid() == uid)
+    }
+
+    /// Pixel data kept as is, uncompressed
+    pub fn is_native(&self) -> bool {
+        matches!(
+            self,
+            Self::ExplicitVrLittleEndian | Self::ImplicitVrLittleEndian | Self::DeflatedExplicitVrLittleEndian
+        )
+    }
+
+    pub fn is_lossy(&self) -> bool {
+        matches!(self, Self::JpegBaseline | Self::Jpeg2000)
+    }
+}
@@ -0,0 +1,793 @@
+use std::collections::BTreeMap;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use flate2::{write::DeflateEncoder, Compression, Crc};
+use jp2k::{Codec, DecodeParams, SampleBuffer, Stream};
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, frame_codestreams, open_file, Confirmation, DataDictionary, File,
+    FileDicomObject, InMemDicomObject, IntoParallelRefIterator, Journal, OpenFileOptions, ParallelIterator, PixelDecoder,
+    PlanarConfiguration, PulseError, PulseErrorKind, Rescale, Result, RunSummary, StandardDataDictionary, Tag,
+};
+use serde::Serialize;
+
+use crate::frames::FrameSelection;
+use crate::window::{frame_element, frame_rescale, is_monochrome, text};
+
+/// Name of the array in the `.npz` archives, `np.load(path)["pixels"]`
+const NPZ_ENTRY: &str = "pixels.npy";
+
+/// Attributes of the sidecar JSON without `--sidecar-tags`
+const DEFAULT_TAGS: [&str; 8] = [
+    "Modality",
+    "StudyInstanceUID",
+    "SeriesInstanceUID",
+    "SeriesDescription",
+    "BodyPartExamined",
+    "PhotometricInterpretation",
+    "SliceThickness",
+    "Manufacturer",
+];
+
+/// File format of the exported arrays
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrayFormat {
+    /// NumPy array
+    Npy,
+    /// NumPy array in a deflated zip archive
+    Npz,
+    /// Values only, the shape and type being in the sidecar
+    Raw,
+}
+
+impl FromStr for ArrayFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "npy" => Ok(Self::Npy),
+            "npz" => Ok(Self::Npz),
+            "raw" => Ok(Self::Raw),
+            _ => Err(format!("Invalid export format '{}', expected npy, npz or raw", s)),
+        }
+    }
+}
+
+impl ArrayFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Npy => "npy",
+            Self::Npz => "npz",
+            Self::Raw => "raw",
+        }
+    }
+}
+
+/// Attributes copied to the sidecar JSON, by keyword: comma-separated keywords or `none`
+#[derive(Clone, Debug, PartialEq)]
+pub struct SidecarTags(Vec<(String, Tag)>);
+
+impl Default for SidecarTags {
+    fn default() -> Self {
+        DEFAULT_TAGS.join(",").parse().unwrap_or(Self(Vec::new()))
+    }
+}
+
+impl FromStr for SidecarTags {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("none") {
+            return Ok(Self(Vec::new()));
+        }
+        s.split(',')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .map(|keyword| {
+                StandardDataDictionary
+                    .by_name(keyword)
+                    .map(|entry| (entry.alias.to_string(), entry.tag.inner()))
+                    .ok_or_else(|| format!("Unknown attribute '{}' in the sidecar tags", keyword))
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Pixel data written as arrays of modality values instead of rendered PNGs
+#[derive(Clone, Debug)]
+pub struct ArrayExport {
+    pub format: ArrayFormat,
+    /// Every series in a single array, its slices along the first axis
+    pub stack: bool,
+    pub tags: SidecarTags,
+}
+
+/// Element type of an array, as NumPy names it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DType {
+    U8,
+    I8,
+    U16,
+    I16,
+    I32,
+    F32,
+}
+
+impl DType {
+    fn stored(bits_allocated: u16, signed: bool) -> Self {
+        match (bits_allocated, signed) {
+            (8, false) => Self::U8,
+            (8, true) => Self::I8,
+            (_, false) => Self::U16,
+            (_, true) => Self::I16,
+        }
+    }
+
+    /// The stored type when it holds every value, else the smallest signed one, else float
+    fn holding(stored: Option<Self>, (min, max): (f64, f64), integral: bool) -> Self {
+        if !integral {
+            return Self::F32;
+        }
+        stored
+            .into_iter()
+            .chain([Self::I16, Self::I32])
+            .find(|dtype| {
+                let (low, high) = dtype.bounds();
+                low <= min && max <= high
+            })
+            .unwrap_or(Self::F32)
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        match self {
+            Self::U8 => (u8::MIN as f64, u8::MAX as f64),
+            Self::I8 => (i8::MIN as f64, i8::MAX as f64),
+            Self::U16 => (u16::MIN as f64, u16::MAX as f64),
+            Self::I16 => (i16::MIN as f64, i16::MAX as f64),
+            Self::I32 => (i32::MIN as f64, i32::MAX as f64),
+            Self::F32 => (f32::MIN as f64, f32::MAX as f64),
+        }
+    }
+
+    fn descr(&self) -> &'static str {
+        match self {
+            Self::U8 => "|u1",
+            Self::I8 => "|i1",
+            Self::U16 => "<u2",
+            Self::I16 => "<i2",
+            Self::I32 => "<i4",
+            Self::F32 => "<f4",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::U8 => "uint8",
+            Self::I8 => "int8",
+            Self::U16 => "uint16",
+            Self::I16 => "int16",
+            Self::I32 => "int32",
+            Self::F32 => "float32",
+        }
+    }
+
+    /// Little endian bytes of the value
+    fn push(&self, value: f64, out: &mut Vec<u8>) {
+        match self {
+            Self::U8 => out.push(value.round() as u8),
+            Self::I8 => out.push(value.round() as i8 as u8),
+            Self::U16 => out.extend_from_slice(&(value.round() as u16).to_le_bytes()),
+            Self::I16 => out.extend_from_slice(&(value.round() as i16).to_le_bytes()),
+            Self::I32 => out.extend_from_slice(&(value.round() as i32).to_le_bytes()),
+            Self::F32 => out.extend_from_slice(&(value as f32).to_le_bytes()),
+        }
+    }
+}
+
+/// First axis of an exported array
+enum Axis {
+    /// Single frame object, a 2D array
+    None,
+    /// Selected frames of a multi-frame object, numbered from 1
+    Frames(Vec<u32>),
+    /// Slices of a series
+    Slices,
+}
+
+/// Index and stored values of each selected frame
+type StoredFrames = Vec<(u32, Vec<i32>)>;
+
+/// Selected frames of an object, with what the sidecar needs
+struct Object {
+    rows: usize,
+    columns: usize,
+    samples_per_pixel: usize,
+    /// Frames of the object, selected or not
+    count: u32,
+    slices: Vec<Slice>,
+    pixel_spacing: Option<[f64; 2]>,
+    /// Spacing Between Slices, else Slice Thickness
+    slice_spacing: Option<f64>,
+    units: Option<String>,
+    tags: BTreeMap<String, Option<String>>,
+}
+
+/// A frame of an object, its stored values and where it lies in the patient
+struct Slice {
+    frame: u32,
+    values: Vec<i32>,
+    stored: DType,
+    rescale: Rescale,
+    /// Lowest and highest modality values the stored bits can give
+    range: (f64, f64),
+    position: Option<[f64; 3]>,
+    orientation: Option<[f64; 6]>,
+    instance: Option<i32>,
+}
+
+impl Slice {
+    fn integral(&self) -> bool {
+        self.rescale.slope.fract() == 0. && self.rescale.intercept.fract() == 0.
+    }
+}
+
+#[derive(Serialize)]
+struct Sidecar<'a> {
+    /// Source of each slice of a stack, or the exported file
+    files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frames: Option<Vec<u32>>,
+    shape: &'a [usize],
+    dtype: &'static str,
+    /// Millimetres between two elements along each spatial axis, null when unknown
+    spacing: Vec<Option<f64>>,
+    orientation: Option<[f64; 6]>,
+    origin: Option<[f64; 3]>,
+    units: Option<String>,
+    tags: &'a BTreeMap<String, Option<String>>,
+}
+
+/// Modality values of the selected frames of a file, written next to `output_path` with its sidecar
+pub fn export_file(
+    input: &Path,
+    output_path: &Path,
+    selection: &FrameSelection,
+    export: &ArrayExport,
+    confirmation: Confirmation,
+) -> Result<()> {
+    let object = read_object(input, selection, &export.tags)?;
+    let slices: Vec<&Slice> = object.slices.iter().collect();
+    // A multi-frame object keeps its frames axis, even with a single frame selected
+    let axis = match object.count {
+        1 => Axis::None,
+        _ => Axis::Frames(slices.iter().map(|s| s.frame + 1).collect()),
+    };
+    write(
+        &output_path.with_extension(export.format.extension()),
+        &object,
+        &slices,
+        vec![input.display().to_string()],
+        axis,
+        export.format,
+        confirmation,
+    )
+}
+
+/// Every series of `files` stacked in a single array named after its Series Instance UID,
+/// slices sorted along the normal of their orientation, else by Instance Number.
+/// Files are grouped from their header, then one series at a time is decoded and written
+pub fn export_series(
+    files: &[PathBuf],
+    out: &Path,
+    selection: &FrameSelection,
+    export: &ArrayExport,
+    confirmation: Confirmation,
+    journal: &Journal,
+    summary: &RunSummary,
+) {
+    let uids: Vec<(&PathBuf, Result<Option<String>>)> = files.par_iter().map(|file| (file, series_uid(file))).collect();
+    let mut series: BTreeMap<String, Vec<&PathBuf>> = BTreeMap::new();
+    for (file, uid) in uids {
+        match uid {
+            Ok(Some(uid)) => series.entry(uid).or_default().push(file),
+            Ok(None) => summary.record(file, Err(unsupported("No Series Instance UID, the file can't be stacked"))),
+            Err(e) => summary.record(file, Err(e)),
+        }
+    }
+
+    let mut done = 0;
+    for (uid, files) in series {
+        // The array holds every file of the series: one left to do and all are read again
+        if files.iter().all(|file| journal.is_done(file).unwrap_or(false)) {
+            summary.skip(files.len());
+            done += 1;
+            continue;
+        }
+        let mut objects = Vec::with_capacity(files.len());
+        let read: Vec<(&PathBuf, Result<Object>)> =
+            files.par_iter().map(|file| (*file, read_object(file, selection, &export.tags))).collect();
+        for (file, object) in read {
+            match object {
+                Ok(object) => objects.push((file, object)),
+                Err(e) => summary.record(file, Err(e)),
+            }
+        }
+        if objects.is_empty() {
+            continue;
+        }
+
+        let outcome = write_series(&uid, &objects, out, export.format, confirmation);
+        for (file, _) in &objects {
+            let outcome = match &outcome {
+                Ok(()) => journal.complete(file),
+                Err(e) => Err(unsupported(&format!("Series {} not stacked: {}", uid, e))),
+            };
+            summary.record(file, outcome);
+        }
+    }
+    if done > 0 {
+        println!("Resuming from {}: {} series already done", journal.path().display(), done);
+    }
+}
+
+/// Series Instance UID of a file, read from its header alone
+fn series_uid(path: &Path) -> Result<Option<String>> {
+    let obj = OpenFileOptions::new().read_until(tags::PIXEL_DATA).open_file(path)?;
+    Ok(obj
+        .get(tags::SERIES_INSTANCE_UID)
+        .and_then(|e| e.to_str().ok())
+        .map(|uid| uid.trim_end_matches(['\0', ' ']).to_string())
+        .filter(|uid| !uid.is_empty()))
+}
+
+fn write_series(
+    uid: &str,
+    objects: &[(&PathBuf, Object)],
+    out: &Path,
+    format: ArrayFormat,
+    confirmation: Confirmation,
+) -> Result<()> {
+    let (_, first) = &objects[0];
+    if let Some((file, _)) = objects.iter().find(|(_, o)| {
+        (o.rows, o.columns, o.samples_per_pixel) != (first.rows, first.columns, first.samples_per_pixel)
+    }) {
+        return Err(unsupported(&format!(
+            "{} isn't {}x{} with {} sample(s) per pixel as the rest of the series",
+            file.display(),
+            first.columns,
+            first.rows,
+            first.samples_per_pixel
+        )));
+    }
+
+    let mut slices: Vec<(&PathBuf, &Slice)> =
+        objects.iter().flat_map(|(file, o)| o.slices.iter().map(move |s| (*file, s))).collect();
+    let normal = slices.first().and_then(|(_, s)| s.orientation).map(normal);
+    match normal {
+        Some(normal) if slices.iter().all(|(_, s)| s.position.is_some()) => {
+            let distance = |s: &Slice| s.position.map_or(0., |p| dot(p, normal));
+            slices.sort_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)));
+        }
+        _ => slices.sort_by_key(|(_, s)| (s.instance, s.frame)),
+    }
+
+    let files = slices.iter().map(|(file, _)| file.display().to_string()).collect();
+    let slices: Vec<&Slice> = slices.into_iter().map(|(_, s)| s).collect();
+    // UIDs are dot-separated, the extension is appended
+    let path = out.join(format!("{}.{}", uid, format.extension()));
+    write(&path, first, &slices, files, Axis::Slices, format, confirmation)
+}
+
+/// Array of `slices` along the first `axis` and its sidecar JSON
+fn write(
+    path: &Path,
+    object: &Object,
+    slices: &[&Slice],
+    files: Vec<String>,
+    axis: Axis,
+    format: ArrayFormat,
+    confirmation: Confirmation,
+) -> Result<()> {
+    if slices.is_empty() {
+        return Err(unsupported("No frame selected"));
+    }
+    let volume = !matches!(axis, Axis::None);
+    let mut shape = vec![object.rows, object.columns];
+    if volume {
+        shape.insert(0, slices.len());
+    }
+    if object.samples_per_pixel > 1 {
+        shape.push(object.samples_per_pixel);
+    }
+
+    let stored = Some(slices[0].stored).filter(|dtype| slices.iter().all(|s| s.stored == *dtype));
+    let range = slices
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), s| (min.min(s.range.0), max.max(s.range.1)));
+    let dtype = DType::holding(stored, range, slices.iter().all(|s| s.integral()));
+
+    let mut data = Vec::with_capacity(shape.iter().product::<usize>() * 4);
+    for slice in slices {
+        for value in &slice.values {
+            dtype.push(slice.rescale.apply(*value as f64), &mut data);
+        }
+    }
+
+    confirmation.check_destination(path)?;
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+    match format {
+        ArrayFormat::Npy => {
+            writer.write_all(&npy_header(&shape, dtype))?;
+            writer.write_all(&data)?;
+        }
+        ArrayFormat::Npz => writer.write_all(&npz([npy_header(&shape, dtype), data].concat())?)?,
+        ArrayFormat::Raw => writer.write_all(&data)?,
+    }
+    writer.flush()?;
+
+    let [row_spacing, column_spacing] = object.pixel_spacing.map_or([None, None], |[r, c]| [Some(r), Some(c)]);
+    let mut spacing = vec![row_spacing, column_spacing];
+    if volume {
+        spacing.insert(0, slice_spacing(slices).or(object.slice_spacing));
+    }
+    let sidecar = Sidecar {
+        files,
+        frames: match axis {
+            Axis::Frames(frames) => Some(frames),
+            _ => None,
+        },
+        shape: &shape,
+        dtype: dtype.name(),
+        spacing,
+        orientation: slices[0].orientation,
+        origin: slices[0].position,
+        units: object.units.clone(),
+        tags: &object.tags,
+    };
+    let sidecar_path = path.with_extension("json");
+    confirmation.check_destination(&sidecar_path)?;
+    serde_json::to_writer_pretty(BufWriter::new(File::create(&sidecar_path)?), &sidecar)
+        .map_err(|e| PulseError::from(std::io::Error::from(e)))
+}
+
+fn read_object(path: &Path, selection: &FrameSelection, tags: &SidecarTags) -> Result<Object> {
+    let obj = open_file(path)?;
+    let int = |tag: Tag| obj.get(tag).and_then(|e| e.to_int::<u32>().ok());
+    let rows = int(tags::ROWS).ok_or_else(|| unsupported("Missing or invalid Rows"))? as usize;
+    let columns = int(tags::COLUMNS).ok_or_else(|| unsupported("Missing or invalid Columns"))? as usize;
+    let samples_per_pixel = int(tags::SAMPLES_PER_PIXEL).unwrap_or(1) as usize;
+    let bits_allocated = int(tags::BITS_ALLOCATED).unwrap_or(0) as u16;
+    if bits_allocated != 8 && bits_allocated != 16 {
+        return Err(unsupported(&format!("{} bits allocated, expected 8 or 16", bits_allocated)));
+    }
+    let bits_stored = int(tags::BITS_STORED).map_or(bits_allocated, |b| b as u16).clamp(1, bits_allocated);
+    let signed = int(tags::PIXEL_REPRESENTATION) == Some(1);
+
+    let (count, frames) = stored_frames(&obj, selection, bits_allocated, bits_stored, signed)?;
+    let size = rows * columns * samples_per_pixel;
+    let (low, high) = if signed {
+        (-(1i64 << (bits_stored - 1)) as f64, ((1i64 << (bits_stored - 1)) - 1) as f64)
+    } else {
+        (0., ((1i64 << bits_stored) - 1) as f64)
+    };
+
+    let monochrome = is_monochrome(&obj);
+    let slices = frames
+        .into_iter()
+        .map(|(frame, mut values)| {
+            if values.len() < size {
+                return Err(unsupported("Frame size doesn't match its rows and columns"));
+            }
+            values.truncate(size);
+            // Colour samples are kept as stored
+            let rescale = if monochrome { frame_rescale(&obj, frame) } else { Rescale::new(1., 0.) };
+            let (a, b) = (rescale.apply(low), rescale.apply(high));
+            Ok(Slice {
+                frame,
+                values,
+                stored: DType::stored(bits_allocated, signed),
+                rescale,
+                range: (a.min(b), a.max(b)),
+                position: floats(&obj, frame, tags::PLANE_POSITION_SEQUENCE, tags::IMAGE_POSITION_PATIENT)
+                    .and_then(|v| v.try_into().ok()),
+                orientation: floats(&obj, frame, tags::PLANE_ORIENTATION_SEQUENCE, tags::IMAGE_ORIENTATION_PATIENT)
+                    .and_then(|v| v.try_into().ok()),
+                instance: obj.get(tags::INSTANCE_NUMBER).and_then(|e| e.to_int::<i32>().ok()),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let first = slices.first().map_or(0, |s| s.frame);
+    let measure = |tag| floats(&obj, first, tags::PIXEL_MEASURES_SEQUENCE, tag);
+    // Hounsfield units unless stated otherwise for CT
+    let units = text(&obj, tags::RESCALE_TYPE)
+        .or_else(|| (monochrome && text(&obj, tags::MODALITY).as_deref() == Some("CT")).then(|| "HU".to_string()));
+
+    Ok(Object {
+        rows,
+        columns,
+        samples_per_pixel,
+        count,
+        pixel_spacing: measure(tags::PIXEL_SPACING).and_then(|v| v.try_into().ok()),
+        slice_spacing: measure(tags::SPACING_BETWEEN_SLICES)
+            .or_else(|| measure(tags::SLICE_THICKNESS))
+            .and_then(|v| v.first().copied()),
+        units,
+        tags: tags
+            .0
+            .iter()
+            .map(|(keyword, tag)| {
+                let value = obj.get(*tag).and_then(|e| e.to_str().ok());
+                (keyword.clone(), value.map(|v| v.trim_end_matches(['\0', ' ']).to_string()))
+            })
+            .collect(),
+        slices,
+    })
+}
+
+/// Stored values of the selected frames, sign extended, colour samples interleaved,
+/// with the number of frames of the object
+fn stored_frames(
+    obj: &FileDicomObject<InMemDicomObject>,
+    selection: &FrameSelection,
+    bits_allocated: u16,
+    bits_stored: u16,
+    signed: bool,
+) -> Result<(u32, StoredFrames)> {
+    let ts = obj.meta().transfer_syntax();
+    if ts == "1.2.840.10008.1.2.4.90" || ts == "1.2.840.10008.1.2.4.91" {
+        let codestreams = frame_codestreams(obj)?;
+        let count = codestreams.len() as u32;
+        let frames = selection
+            .indices(count)
+            .into_iter()
+            .map(|frame| {
+                let stream = Stream::from_bytes(&codestreams[frame as usize])?;
+                let codec = Codec::create(jp2k::CODEC_FORMAT::OPJ_CODEC_J2K)?;
+                Ok((frame, SampleBuffer::build(codec, stream, DecodeParams::default())?.samples))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return Ok((count, frames));
+    }
+
+    let image = obj.decode_pixel_data()?;
+    let count = image.number_of_frames();
+    let planar = image.samples_per_pixel() > 1 && image.planar_configuration() == PlanarConfiguration::PixelFirst;
+    let mask = (1u32 << bits_stored) - 1;
+    let shift = 32 - bits_stored as u32;
+    let extend = |v: u32| if signed { (((v & mask) << shift) as i32) >> shift } else { (v & mask) as i32 };
+    let frames = selection
+        .indices(count)
+        .into_iter()
+        .map(|frame| {
+            let bytes = image.frame_data(frame)?;
+            let values: Vec<i32> = match bits_allocated {
+                8 => bytes.iter().map(|b| extend(*b as u32)).collect(),
+                _ => bytes.chunks_exact(2).map(|b| extend(u16::from_le_bytes([b[0], b[1]]) as u32)).collect(),
+            };
+            let values = if planar {
+                let samples = image.samples_per_pixel() as usize;
+                let plane = values.len() / samples;
+                (0..plane).flat_map(|pixel| (0..samples).map(|s| values[s * plane + pixel]).collect::<Vec<_>>()).collect()
+            } else {
+                values
+            };
+            Ok((frame, values))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((count, frames))
+}
+
+/// Mean distance between consecutive slices along their normal
+fn slice_spacing(slices: &[&Slice]) -> Option<f64> {
+    let normal = normal(slices.first()?.orientation?);
+    let first = dot(slices.first()?.position?, normal);
+    let last = dot(slices.last()?.position?, normal);
+    // Frames sharing the position of their object have none
+    Some((last - first).abs() / (slices.len() - 1) as f64).filter(|spacing| slices.len() > 1 && *spacing > 0.)
+}
+
+fn normal(orientation: [f64; 6]) -> [f64; 3] {
+    let [rx, ry, rz, cx, cy, cz] = orientation;
+    [ry * cz - rz * cy, rz * cx - rx * cz, rx * cy - ry * cx]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn floats(obj: &InMemDicomObject, frame: u32, sequence: Tag, tag: Tag) -> Option<Vec<f64>> {
+    frame_element(obj, frame, sequence, tag).and_then(|e| e.to_multi_float64().ok())
+}
+
+/// NPY 1.0 header: magic, version, then the array description padded to 64 bytes
+fn npy_header(shape: &[usize], dtype: DType) -> Vec<u8> {
+    let dimensions: Vec<String> = shape.iter().map(usize::to_string).collect();
+    let shape = match dimensions.len() {
+        1 => format!("{},", dimensions[0]),
+        _ => dimensions.join(", "),
+    };
+    let mut description =
+        format!("{{'descr': '{}', 'fortran_order': False, 'shape': ({}), }}", dtype.descr(), shape);
+    let padding = (64 - (10 + description.len() + 1) % 64) % 64;
+    description.push_str(&" ".repeat(padding));
+    description.push('\n');
+
+    let mut header = b"\x93NUMPY\x01\x00".to_vec();
+    header.extend_from_slice(&(description.len() as u16).to_le_bytes());
+    header.extend_from_slice(description.as_bytes());
+    header
+}
+
+/// Zip archive of the deflated NPY, as `numpy.savez_compressed` writes it
+fn npz(npy: Vec<u8>) -> Result<Vec<u8>> {
+    let mut crc = Crc::new();
+    crc.update(&npy);
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&npy)?;
+    let compressed = encoder.finish()?;
+    let size = |n: usize| u32::try_from(n).map_err(|_| unsupported("Array over 4 GiB, too large for a .npz"));
+
+    // Version needed, flags, deflate, time, date (1980-01-01), CRC, sizes and name length,
+    // shared by the local and central headers
+    let name = NPZ_ENTRY.as_bytes();
+    let mut fields = Vec::new();
+    for value in [20u16, 0, 8, 0, 0x21] {
+        fields.extend_from_slice(&value.to_le_bytes());
+    }
+    for value in [crc.sum(), size(compressed.len())?, size(npy.len())?] {
+        fields.extend_from_slice(&value.to_le_bytes());
+    }
+    fields.extend_from_slice(&(name.len() as u16).to_le_bytes());
+
+    let mut zip = Vec::with_capacity(compressed.len() + 128);
+    zip.extend_from_slice(&0x04034b50u32.to_le_bytes());
+    zip.extend_from_slice(&fields);
+    zip.extend_from_slice(&0u16.to_le_bytes());
+    zip.extend_from_slice(name);
+    zip.extend_from_slice(&compressed);
+
+    let central = size(zip.len())?;
+    zip.extend_from_slice(&0x02014b50u32.to_le_bytes());
+    zip.extend_from_slice(&20u16.to_le_bytes());
+    zip.extend_from_slice(&fields);
+    // Extra, comment, disk, internal and external attributes, then the local header at 0
+    zip.extend_from_slice(&[0; 16]);
+    zip.extend_from_slice(name);
+
+    let central_size = size(zip.len())? - central;
+    zip.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    for value in [0u16, 0, 1, 1] {
+        zip.extend_from_slice(&value.to_le_bytes());
+    }
+    zip.extend_from_slice(&central_size.to_le_bytes());
+    zip.extend_from_slice(&central.to_le_bytes());
+    zip.extend_from_slice(&0u16.to_le_bytes());
+    Ok(zip)
+}
+
+fn unsupported(message: &str) -> PulseError {
+    PulseError::new(PulseErrorKind::UnsupportedPixelData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::DeflateDecoder;
+    use pulsedcm_core::{DataElement, FileMetaTableBuilder, PrimitiveValue, VR};
+    use std::io::Read;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+    }
+
+    #[test]
+    fn npy_header_padding() {
+        for (shape, expected) in [(vec![3], "(3,)"), (vec![512, 512], "(512, 512)"), (vec![120, 512, 512, 3], "(120, 512, 512, 3)")] {
+            let header = npy_header(&shape, DType::I16);
+            assert_eq!(&header[..8], b"\x93NUMPY\x01\x00");
+            assert_eq!(header.len() % 64, 0);
+            assert_eq!(u16_at(&header, 8) as usize, header.len() - 10);
+            let description = std::str::from_utf8(&header[10..]).unwrap();
+            assert!(description.ends_with(" \n"));
+            assert!(description.contains(&format!("'shape': {}", expected)), "{}", description);
+            assert!(description.starts_with("{'descr': '<i2', 'fortran_order': False,"));
+        }
+    }
+
+    #[test]
+    fn npz_layout() {
+        let npy = [npy_header(&[4, 4], DType::U8), (0..16).collect()].concat();
+        let zip = npz(npy.clone()).unwrap();
+        let name = NPZ_ENTRY.as_bytes();
+
+        // Local header, then the deflated NPY
+        assert_eq!(u32_at(&zip, 0), 0x04034b50);
+        assert_eq!(u16_at(&zip, 8), 8);
+        let mut crc = Crc::new();
+        crc.update(&npy);
+        assert_eq!(u32_at(&zip, 14), crc.sum());
+        let compressed = u32_at(&zip, 18) as usize;
+        assert_eq!(u32_at(&zip, 22) as usize, npy.len());
+        assert_eq!(&zip[30..30 + name.len()], name);
+        let data = 30 + name.len();
+        let mut inflated = Vec::new();
+        DeflateDecoder::new(&zip[data..data + compressed]).read_to_end(&mut inflated).unwrap();
+        assert_eq!(inflated, npy);
+
+        // Central directory, pointing at the local header, then the end record
+        let central = data + compressed;
+        assert_eq!(u32_at(&zip, central), 0x02014b50);
+        assert_eq!(u32_at(&zip, central + 42), 0);
+        assert_eq!(&zip[central + 46..central + 46 + name.len()], name);
+        let end = central + 46 + name.len();
+        assert_eq!(u32_at(&zip, end), 0x06054b50);
+        assert_eq!(u16_at(&zip, end + 10), 1);
+        assert_eq!(u32_at(&zip, end + 12) as usize, end - central);
+        assert_eq!(u32_at(&zip, end + 16) as usize, central);
+        assert_eq!(zip.len(), end + 22);
+    }
+
+    /// Single slice of 2x2 pixels at `z`
+    fn write_slice(path: &Path, series: &str, z: f64) {
+        let obj = InMemDicomObject::from_element_iter([
+            DataElement::new(tags::SOP_CLASS_UID, VR::UI, PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.2")),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, PrimitiveValue::from(format!("{}.{}", series, z))),
+            DataElement::new(tags::SERIES_INSTANCE_UID, VR::UI, PrimitiveValue::from(series)),
+            DataElement::new(tags::IMAGE_POSITION_PATIENT, VR::DS, PrimitiveValue::from(format!("0\\0\\{}", z))),
+            DataElement::new(tags::IMAGE_ORIENTATION_PATIENT, VR::DS, PrimitiveValue::from("1\\0\\0\\0\\1\\0")),
+            DataElement::new(tags::SAMPLES_PER_PIXEL, VR::US, PrimitiveValue::from(1u16)),
+            DataElement::new(tags::PHOTOMETRIC_INTERPRETATION, VR::CS, PrimitiveValue::from("MONOCHROME2")),
+            DataElement::new(tags::ROWS, VR::US, PrimitiveValue::from(2u16)),
+            DataElement::new(tags::COLUMNS, VR::US, PrimitiveValue::from(2u16)),
+            DataElement::new(tags::BITS_ALLOCATED, VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(tags::BITS_STORED, VR::US, PrimitiveValue::from(8u16)),
+            DataElement::new(tags::HIGH_BIT, VR::US, PrimitiveValue::from(7u16)),
+            DataElement::new(tags::PIXEL_REPRESENTATION, VR::US, PrimitiveValue::from(0u16)),
+            DataElement::new(tags::PIXEL_DATA, VR::OB, PrimitiveValue::from(vec![z as u8; 4])),
+        ]);
+        let meta = FileMetaTableBuilder::new()
+            .transfer_syntax("1.2.840.10008.1.2.1")
+            .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.2")
+            .media_storage_sop_instance_uid(format!("{}.{}", series, z));
+        obj.with_meta(meta).unwrap().write_to_file(path).unwrap();
+    }
+
+    #[test]
+    fn resume_whole_series() {
+        let dir = std::env::temp_dir().join(format!("pulsedcm-series-{}", std::process::id()));
+        let out = dir.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+        let files: Vec<PathBuf> = [("1.2.3", 2.), ("1.2.3", 1.), ("1.2.4", 1.)]
+            .iter()
+            .enumerate()
+            .map(|(index, (series, z))| {
+                let path = dir.join(format!("{}.dcm", index));
+                write_slice(&path, series, *z);
+                path
+            })
+            .collect();
+        let export = ArrayExport { format: ArrayFormat::Raw, stack: true, tags: SidecarTags(Vec::new()) };
+        let selection = FrameSelection::default();
+
+        // Interrupted with a file of 1.2.3 and all of 1.2.4 recorded
+        let journal = Journal::open(out.join("journal.csv"), false).unwrap();
+        journal.complete(&files[0]).unwrap();
+        journal.complete(&files[2]).unwrap();
+        drop(journal);
+
+        let journal = Journal::open(out.join("journal.csv"), true).unwrap();
+        let summary = RunSummary::new();
+        export_series(&files, &out, &selection, &export, Confirmation::Yes, &journal, &summary);
+        assert_eq!(std::fs::read(out.join("1.2.3.raw")).unwrap(), [1, 1, 1, 1, 2, 2, 2, 2]);
+        assert!(!out.join("1.2.4.raw").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
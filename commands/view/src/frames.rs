@@ -0,0 +1,256 @@
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops, Delay, DynamicImage, Frame};
+use pulsedcm_core::{dicom_dictionary_std::tags, Confirmation, InMemDicomObject, PulseError, PulseErrorKind, Result};
+
+/// Delay between frames without a frame time or rate in the file
+const DEFAULT_DELAY_MS: u32 = 100;
+
+/// Frames to export, numbered from 1 as in DICOM: `all`, `N`, or `FIRST-LAST`,
+/// each with an optional `:STEP`, e.g. `1-100:5`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameSelection {
+    first: u32,
+    last: Option<u32>, // Up to the last frame of the object if none
+    step: u32,
+}
+
+impl Default for FrameSelection {
+    fn default() -> Self {
+        Self { first: 1, last: None, step: 1 }
+    }
+}
+
+impl FromStr for FrameSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!("Invalid frames '{}', expected all, N or FIRST-LAST with an optional :STEP", s);
+        let number = |v: &str| v.trim().parse::<u32>().ok().filter(|n| *n > 0).ok_or_else(invalid);
+
+        let (range, step) = match s.trim().split_once(':') {
+            Some((range, step)) => (range, number(step)?),
+            None => (s.trim(), 1),
+        };
+        let (first, last) = match range.to_lowercase().as_str() {
+            "all" | "" => (1, None),
+            range => match range.split_once('-') {
+                Some((first, last)) => (number(first)?, Some(number(last)?)),
+                None => (number(range)?, Some(number(range)?)),
+            },
+        };
+        if last.is_some_and(|last| last < first) {
+            return Err(invalid());
+        }
+        Ok(Self { first, last, step })
+    }
+}
+
+impl FrameSelection {
+    /// Indices, from 0, of the selected frames among `count`
+    pub fn indices(&self, count: u32) -> Vec<u32> {
+        let last = self.last.unwrap_or(count).min(count);
+        (self.first..=last).step_by(self.step as usize).map(|n| n - 1).collect()
+    }
+
+    pub fn step(&self) -> u32 {
+        self.step
+    }
+}
+
+/// How the selected frames of an object are written
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrameOutput {
+    /// One PNG per frame, numbered for multi-frame objects
+    #[default]
+    Png,
+    Gif,
+    Apng,
+    /// Every frame on a grid, in a single PNG
+    Sheet,
+}
+
+impl FromStr for FrameOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "gif" => Ok(Self::Gif),
+            "apng" => Ok(Self::Apng),
+            "sheet" => Ok(Self::Sheet),
+            _ => Err(format!("Invalid frame output '{}', expected png, gif, apng or sheet", s)),
+        }
+    }
+}
+
+/// Time between two frames of the object, in milliseconds
+pub fn frame_delay(obj: &InMemDicomObject) -> u32 {
+    let float = |tag| obj.get(tag).and_then(|e| e.to_float64().ok()).filter(|v| *v > 0.);
+    float(tags::FRAME_TIME)
+        .or_else(|| float(tags::RECOMMENDED_DISPLAY_FRAME_RATE).map(|rate| 1000. / rate))
+        .or_else(|| float(tags::CINE_RATE).map(|rate| 1000. / rate))
+        .map(|delay| delay.round() as u32)
+        .unwrap_or(DEFAULT_DELAY_MS)
+}
+
+/// Render then write the selected frames (`indices`) of an object of `count` frames,
+/// one at a time, returning the files written
+pub fn write_frames(
+    indices: &[u32],
+    count: u32,
+    mut render: impl FnMut(u32) -> Result<DynamicImage>,
+    output_path: &Path,
+    output: FrameOutput,
+    delay_ms: u32,
+    confirmation: Confirmation,
+) -> Result<Vec<PathBuf>> {
+    let Some(&first) = indices.first() else {
+        return Err(PulseError::new(PulseErrorKind::UnsupportedPixelData, "No frame selected"));
+    };
+    let single = |path: PathBuf| -> Result<PathBuf> {
+        confirmation.check_destination(&path)?;
+        Ok(path)
+    };
+
+    match output {
+        FrameOutput::Png if count == 1 => {
+            let path = single(output_path.to_path_buf())?;
+            render(first)?.save(&path)?;
+            Ok(vec![path])
+        }
+        FrameOutput::Png => {
+            let width = count.to_string().len();
+            let stem = output_path.file_stem().unwrap_or_default().to_string_lossy();
+            indices
+                .iter()
+                .map(|index| {
+                    let path = single(output_path.with_file_name(format!("{}_{:0width$}.png", stem, index + 1)))?;
+                    render(*index)?.save(&path)?;
+                    Ok(path)
+                })
+                .collect()
+        }
+        FrameOutput::Gif => {
+            let path = single(output_path.with_extension("gif"))?;
+            let mut encoder = GifEncoder::new(BufWriter::new(File::create(&path)?));
+            encoder.set_repeat(Repeat::Infinite)?;
+            for index in indices {
+                let image = render(*index)?;
+                encoder.encode_frame(Frame::from_parts(image.to_rgba8(), 0, 0, Delay::from_numer_denom_ms(delay_ms, 1)))?;
+            }
+            Ok(vec![path])
+        }
+        FrameOutput::Apng => {
+            let path = single(output_path.with_extension("png"))?;
+            write_apng(indices, render, &path, delay_ms)?;
+            Ok(vec![path])
+        }
+        FrameOutput::Sheet => {
+            let stem = output_path.file_stem().unwrap_or_default().to_string_lossy();
+            let path = single(output_path.with_file_name(format!("{}_sheet.png", stem)))?;
+            contact_sheet(indices, render)?.save(&path)?;
+            Ok(vec![path])
+        }
+    }
+}
+
+fn write_apng(
+    indices: &[u32],
+    mut render: impl FnMut(u32) -> Result<DynamicImage>,
+    path: &Path,
+    delay_ms: u32,
+) -> Result<()> {
+    // The first frame gives the size of the animation
+    let first = render(indices[0])?;
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), first.width(), first.height());
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(indices.len() as u32, 0).map_err(apng_error)?;
+    encoder.set_frame_delay(delay_ms.min(u16::MAX as u32) as u16, 1000).map_err(apng_error)?;
+    let mut writer = encoder.write_header().map_err(apng_error)?;
+    writer.write_image_data(first.to_rgba8().as_raw()).map_err(apng_error)?;
+    drop(first);
+    for index in &indices[1..] {
+        writer.write_image_data(render(*index)?.to_rgba8().as_raw()).map_err(apng_error)?;
+    }
+    writer.finish().map_err(apng_error)
+}
+
+fn apng_error(e: png::EncodingError) -> PulseError {
+    PulseError::new(PulseErrorKind::IO(io::Error::other(e)), "Animated PNG error")
+}
+
+/// Frames left to right then top to bottom, on a grid as square as possible
+fn contact_sheet(indices: &[u32], mut render: impl FnMut(u32) -> Result<DynamicImage>) -> Result<DynamicImage> {
+    let columns = (indices.len() as f64).sqrt().ceil() as u32;
+    let rows = (indices.len() as u32).div_ceil(columns);
+    let mut sheet: Option<DynamicImage> = None;
+    for (position, index) in indices.iter().enumerate() {
+        let image = render(*index)?;
+        let (width, height) = (image.width(), image.height());
+        // Sized and typed after the first frame
+        let sheet = sheet.get_or_insert_with(|| DynamicImage::new(width * columns, height * rows, image.color()));
+        let position = position as u32;
+        let (x, y) = ((position % columns) * width, (position / columns) * height);
+        imageops::replace(sheet, &image, x as i64, y as i64);
+    }
+    // At least the first frame was rendered
+    Ok(sheet.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    fn frame(index: u32) -> Result<DynamicImage> {
+        Ok(DynamicImage::ImageLuma8(GrayImage::from_pixel(4, 3, Luma([index as u8 * 50]))))
+    }
+
+    #[test]
+    fn parse_selections() {
+        let indices = |s: &str| s.parse::<FrameSelection>().map(|selection| selection.indices(12));
+        assert_eq!(indices("all").unwrap(), (0..12).collect::<Vec<_>>());
+        assert_eq!(indices("3").unwrap(), [2]);
+        assert_eq!(indices("2-10:3").unwrap(), [1, 4, 7]);
+        assert_eq!(indices("10-100:2").unwrap(), [9, 11]);
+        for invalid in ["5-2", "0", "a-b", "1-3:0"] {
+            assert!(indices(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn write_frames_one_at_a_time() {
+        let dir = std::env::temp_dir().join(format!("pulsedcm-frames-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("IM1.png");
+
+        let mut rendered = Vec::new();
+        let render = |index| {
+            rendered.push(index);
+            frame(index)
+        };
+        let written = write_frames(&[0, 2, 4], 12, render, &output, FrameOutput::Png, 40, Confirmation::Yes).unwrap();
+        assert_eq!(rendered, [0, 2, 4]);
+        assert_eq!(written, ["IM1_01.png", "IM1_03.png", "IM1_05.png"].map(|name| dir.join(name)));
+        assert_eq!(image::open(&written[1]).unwrap().to_luma8().get_pixel(0, 0), &Luma([100]));
+
+        let written = write_frames(&[0, 1, 2, 3, 4], 12, frame, &output, FrameOutput::Sheet, 40, Confirmation::Yes).unwrap();
+        let sheet = image::open(&written[0]).unwrap().to_luma8();
+        assert_eq!(sheet.dimensions(), (12, 6));
+        assert_eq!(sheet.get_pixel(4, 3), &Luma([200]));
+
+        for output_kind in [FrameOutput::Gif, FrameOutput::Apng] {
+            let written = write_frames(&[0, 1], 12, frame, &output, output_kind, 40, Confirmation::Yes).unwrap();
+            assert!(written[0].exists());
+        }
+        // An existing frame is kept with --no-clobber
+        assert!(write_frames(&[0], 12, frame, &output, FrameOutput::Png, 40, Confirmation::NoClobber).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
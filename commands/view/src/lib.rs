@@ -5,15 +5,30 @@ use std::process::Command;
 
 use tempfile::TempDir;
 
-use jp2k::{Codec, DecodeParams, ImageBuffer, Stream};
+use jp2k::{Codec, DecodeParams, SampleBuffer, Stream};
 use image;
 
+pub mod array;
+pub mod frames;
+pub mod window;
+
+use crate::array::{export_file, export_series, ArrayExport};
+use crate::frames::{frame_delay, write_frames, FrameOutput, FrameSelection};
+use crate::window::{is_monochrome, Display, Windowing};
+
 pub fn run(
     files: Vec<PathBuf>,
     open: u8, 
     temp: bool,
     out: PathBuf,
-    jobs: usize
+    windowing: Windowing,
+    selection: FrameSelection,
+    frame_output: FrameOutput,
+    export: Option<ArrayExport>,
+    jobs: usize,
+    confirmation: Confirmation,
+    resume: bool,
+    summary: &RunSummary,
 ) -> Result<()> {
     let mut open: u8 = open;
     let is_temp: bool = temp;
@@ -21,8 +36,7 @@ pub fn run(
 
     let thread_pool = rayon::ThreadPoolBuilder::new()
         .num_threads(jobs as usize)
-        .build()
-        .unwrap();
+        .build()?;
 
     if is_temp {
         match TempDir::new() {
@@ -30,21 +44,19 @@ pub fn run(
                 if open <= 0 { open = 1; }
                 let tmp_path = tmp_dir.into_path();
 
-                let _ = thread_pool.install(|| -> Result<()> {
-                    let _ = files.par_iter().enumerate().try_for_each(|(idx, file)| -> Result<()> {
+                thread_pool.install(|| {
+                    files.par_iter().enumerate().for_each(|(idx, file)| {
                         println!("{}", file.as_os_str().to_str().unwrap_or_default());
 
                         let mut input_path = PathBuf::from(file);
                         let mut out_clone = tmp_path.clone();
 
 
-                        view_processing(&mut input_path, &mut out_clone, idx < open as usize)
-                            .unwrap_or_else(|_e| {
-                                eprintln!("Can't process {} : {}", input_path.display(), _e);
-                            });
-                    Ok(())
+                        summary.record(
+                            file,
+                            view_processing(&mut input_path, &mut out_clone, idx < open as usize, &windowing, &selection, frame_output, confirmation),
+                        );
                     });
-                    Ok(())
                 });
             }
             Err(e) => {
@@ -54,17 +66,28 @@ pub fn run(
         println!("\x1b[1m>> \x1b[0mPress Enter to exit and delete temporary files...");
         let _ = std::io::stdin().read_line(&mut String::new());
     } else {
+        // Asked once, before the workers start
+        confirmation.prepare_output_dir(&out)?;
+        let journal = Journal::for_output(&out, "view", resume)?;
+        // A series is written again from all of its files unless they're all done
+        if let Some(export) = export.as_ref().filter(|export| export.stack) {
+            thread_pool.install(|| export_series(&files, &out, &selection, export, confirmation, &journal, summary));
+            return Ok(());
+        }
+        let files = journal.pending(files, summary)?;
         thread_pool.install(|| {
             files.par_iter().enumerate().for_each(|(idx, file)| {
 
                 let mut input_path = PathBuf::from(file);
                 let mut output_path = out.clone();
 
-                view_processing(&mut input_path, &mut output_path, idx < open as usize).unwrap_or_else(
-                    |_e| {
-                        eprintln!("Can't process {} : {}", input_path.display(), _e);
-                    },
-                );
+                let outcome = match &export {
+                    Some(export) => output_handling(&input_path, &mut output_path)
+                        .and_then(|_| export_file(&input_path, &output_path, &selection, export, confirmation)),
+                    None => view_processing(&mut input_path, &mut output_path, idx < open as usize, &windowing, &selection, frame_output, confirmation),
+                }
+                .and_then(|_| journal.complete(file));
+                summary.record(file, outcome);
             });
         });
     }
@@ -75,6 +98,10 @@ fn view_processing(
     input_path: &mut PathBuf,
     output_path: &mut PathBuf,
     is_to_open: bool,
+    windowing: &Windowing,
+    selection: &FrameSelection,
+    frame_output: FrameOutput,
+    confirmation: Confirmation,
 ) -> Result<()> {
     // let dinput_path = input_path.to_str()?;
     let obj = open_file(input_path.as_path())?;
@@ -82,21 +109,52 @@ fn view_processing(
     output_handling(input_path, output_path)?;
 
     let ts = obj.meta().transfer_syntax();
-    if ts == "1.2.840.10008.1.2.4.90" || ts == "1.2.840.10008.1.2.4.91"{
-        let buff: Vec<u8> = build_byte_buffer(obj)?;
-        handle_byte_to_jp2k(buff, output_path.to_str().unwrap())?;
-
+    // The frames skipped still take their time
+    let delay = frame_delay(&obj) * selection.step();
+    // Each frame is decoded and written before the next one
+    let written = if ts == "1.2.840.10008.1.2.4.90" || ts == "1.2.840.10008.1.2.4.91"{
+        let codestreams = frame_codestreams(&obj)?;
+        let count = codestreams.len() as u32;
+        let render = |frame: u32| handle_byte_to_jp2k(&obj, &codestreams[frame as usize], frame, windowing);
+        write_frames(&selection.indices(count), count, render, output_path, frame_output, delay, confirmation)?
     } else {
-        let image = obj.decode_pixel_data()?;
-        let dynamic_image = image.to_dynamic_image(0)?;
-        dynamic_image.save(&output_path)?;
-    }
+        let count = obj
+            .get(dicom_dictionary_std::tags::NUMBER_OF_FRAMES)
+            .and_then(|e| e.to_int::<u32>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(1);
+        let render = |frame: u32| render_frame(&obj, &obj.decode_pixel_data_frame(frame)?, frame, windowing);
+        write_frames(&selection.indices(count), count, render, output_path, frame_output, delay, confirmation)?
+    };
     if is_to_open {
-        open_image(output_path.to_str().unwrap());
+        if let Some(path) = written.first() {
+            open_image(path.to_str().unwrap_or_default());
+        }
     }
     Ok(())
 }
 
+/// Render the `frame` of the object, `image` holding that frame alone
+fn render_frame(
+    obj: &FileDicomObject<InMemDicomObject>,
+    image: &DecodedPixelData,
+    frame: u32,
+    windowing: &Windowing,
+) -> Result<image::DynamicImage> {
+    if !is_monochrome(obj) {
+        return Ok(image.to_dynamic_image(0)?);
+    }
+    // Stored values, the rescale being part of the display
+    let options = ConvertOptions::new().with_modality_lut(ModalityLutOption::Override(Rescale::new(1., 0.)));
+    let samples: Vec<f64> = image.to_vec_frame_with_options(0, &options)?;
+    let grey = Display::new(obj, frame, windowing)?.render(&samples);
+    Ok(image::DynamicImage::ImageLuma8(
+        image::GrayImage::from_raw(image.columns(), image.rows(), grey).ok_or_else(|| {
+            PulseError::new(PulseErrorKind::UnsupportedPixelData, "Frame size doesn't match its rows and columns")
+        })?,
+    ))
+}
+
 fn open_image(path: &str) {
     let result = if cfg!(target_os = "windows") {
         Command::new("cmd").args(&["/C", "start", "", path]).spawn()
@@ -117,35 +175,36 @@ fn open_image(path: &str) {
 }
 
 
-fn build_byte_buffer(obj: FileDicomObject<InMemDicomObject>) -> Result<Vec<u8>>  {
-    let pixel_data = obj.element_by_name("PixelData").unwrap();
-    let buff: Result<Vec<u8>> = match pixel_data.value() {
-        DicomValue::Primitive(p) => Ok(p.to_bytes().into_owned().to_vec()),
-        DicomValue::PixelSequence(seq) => Ok(seq.fragments().concat()),
-        _ => {
-            return Err(PulseError::new(
-                    PulseErrorKind::UnsupportedPixelData, 
-                    "The output from jp2k isn't supported",
-            ));
-        },
-    };
-        buff
-}
-
-fn handle_byte_to_jp2k(buff: Vec<u8>, output_path: &str) -> Result<()> {
-    let stream = Stream::from_bytes(&buff)?;
+fn handle_byte_to_jp2k(
+    obj: &FileDicomObject<InMemDicomObject>,
+    buff: &[u8],
+    frame: u32,
+    windowing: &Windowing,
+) -> Result<image::DynamicImage> {
+    let stream = Stream::from_bytes(buff)?;
     let codec = Codec::create(jp2k::CODEC_FORMAT::OPJ_CODEC_J2K)?;
 
-    let img_buf = ImageBuffer::build(codec, stream, DecodeParams::default())?;
+    let img_buf = SampleBuffer::build(codec, stream, DecodeParams::default())?;
+    let size_error = || {
+        PulseError::new(PulseErrorKind::UnsupportedPixelData, "Frame size doesn't match its rows and columns")
+    };
     let dyn_img = match img_buf.num_bands {
+        // Full precision samples, windowed as any other monochrome frame
+        1 if is_monochrome(obj) => {
+            let samples: Vec<f64> = img_buf.samples.iter().map(|x| *x as f64).collect();
+            let grey = Display::new(obj, frame, windowing)?.render(&samples);
+            image::DynamicImage::ImageLuma8(
+                image::GrayImage::from_raw(img_buf.width, img_buf.height, grey).ok_or_else(size_error)?,
+            )
+        }
         1 => image::DynamicImage::ImageLuma8(
-            image::GrayImage::from_raw(img_buf.width, img_buf.height, img_buf.buffer).unwrap(),
+            image::GrayImage::from_raw(img_buf.width, img_buf.height, img_buf.to_u8()).ok_or_else(size_error)?,
         ),
         3 => image::DynamicImage::ImageRgb8(
-            image::RgbImage::from_raw(img_buf.width, img_buf.height, img_buf.buffer).unwrap(),
+            image::RgbImage::from_raw(img_buf.width, img_buf.height, img_buf.to_u8()).ok_or_else(size_error)?,
         ),
         4 => image::DynamicImage::ImageRgba8(
-            image::RgbaImage::from_raw(img_buf.width, img_buf.height, img_buf.buffer).unwrap(),
+            image::RgbaImage::from_raw(img_buf.width, img_buf.height, img_buf.to_u8()).ok_or_else(size_error)?,
         ),
         _ => {
             return Err(PulseError::new(
@@ -155,6 +214,5 @@ fn handle_byte_to_jp2k(buff: Vec<u8>, output_path: &str) -> Result<()> {
         }
     };
 
-    dyn_img.save(output_path)?;
-    Ok(())
+    Ok(dyn_img)
 }
@@ -0,0 +1,336 @@
+use std::str::FromStr;
+
+use pulsedcm_core::{
+    dicom_dictionary_std::tags, InMemDicomObject, InMemElement, PrimitiveValue, PulseError,
+    PulseErrorKind, Rescale, Result, Tag, VoiLutFunction, WindowLevel, WindowLevelTransform,
+};
+
+/// Window applied to the monochrome images, `File` by default
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Windowing {
+    /// Window of the file, else its VOI LUT, else min/max
+    #[default]
+    File,
+    /// VOI LUT Sequence of the file
+    VoiLut,
+    /// Lowest to highest value of the frame
+    Auto,
+    Preset(Preset),
+    Custom(WindowLevel),
+}
+
+/// Usual CT windows, in Hounsfield units
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preset {
+    Lung,
+    Bone,
+    Brain,
+    Abdomen,
+}
+
+impl Preset {
+    fn window(&self) -> WindowLevel {
+        let (center, width) = match self {
+            Self::Lung => (-600., 1500.),
+            Self::Bone => (400., 1800.),
+            Self::Brain => (40., 80.),
+            Self::Abdomen => (40., 400.),
+        };
+        WindowLevel { center, width }
+    }
+}
+
+impl FromStr for Windowing {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "file" => Ok(Self::File),
+            "voi-lut" => Ok(Self::VoiLut),
+            "auto" => Ok(Self::Auto),
+            "lung" => Ok(Self::Preset(Preset::Lung)),
+            "bone" => Ok(Self::Preset(Preset::Bone)),
+            "brain" => Ok(Self::Preset(Preset::Brain)),
+            "abdomen" => Ok(Self::Preset(Preset::Abdomen)),
+            other => {
+                let parse = |v: &str| v.trim().parse::<f64>().ok();
+                match other.split_once(',').map(|(c, w)| (parse(c), parse(w))) {
+                    // A linear window is at least one value wide
+                    Some((Some(center), Some(width))) if width >= 1. => {
+                        Ok(Self::Custom(WindowLevel { center, width }))
+                    }
+                    _ => Err(format!(
+                        "Invalid window '{}', expected file, voi-lut, auto, lung, bone, brain, abdomen or CENTER,WIDTH",
+                        s
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Grey levels of a monochrome frame: modality rescale, VOI window or LUT, then presentation
+#[derive(Debug)]
+pub struct Display {
+    rescale: Rescale,
+    voi: Voi,
+    invert: bool,
+}
+
+#[derive(Debug)]
+enum Voi {
+    Window(WindowLevelTransform),
+    Lut(VoiLut),
+    Auto,
+}
+
+#[derive(Debug)]
+struct VoiLut {
+    first: f64, // Value mapped to the first entry
+    max: f64,
+    data: Vec<u16>,
+}
+
+pub fn is_monochrome(obj: &InMemDicomObject) -> bool {
+    matches!(text(obj, tags::PHOTOMETRIC_INTERPRETATION).as_deref(), Some("MONOCHROME1" | "MONOCHROME2"))
+}
+
+impl Display {
+    pub fn new(obj: &InMemDicomObject, frame: u32, windowing: &Windowing) -> Result<Self> {
+        let float = |sequence, tag| frame_float(obj, frame, sequence, tag);
+        let rescale = frame_rescale(obj, frame);
+
+        let file_window = || -> Option<Voi> {
+            let center = float(tags::FRAME_VOILUT_SEQUENCE, tags::WINDOW_CENTER)?;
+            let width = float(tags::FRAME_VOILUT_SEQUENCE, tags::WINDOW_WIDTH)?;
+            let function = frame_element(obj, frame, tags::FRAME_VOILUT_SEQUENCE, tags::VOILUT_FUNCTION)
+                .and_then(|e| e.to_str().ok())
+                .and_then(|f| VoiLutFunction::try_from(f.trim()).ok())
+                .unwrap_or_default();
+            Some(Voi::Window(WindowLevelTransform::new(function, WindowLevel { center, width })))
+        };
+        let voi = match windowing {
+            Windowing::File => file_window()
+                .or_else(|| VoiLut::from_object(obj).map(Voi::Lut))
+                .unwrap_or(Voi::Auto),
+            Windowing::VoiLut => VoiLut::from_object(obj).map(Voi::Lut).ok_or_else(|| {
+                PulseError::new(PulseErrorKind::UnsupportedPixelData, "No VOI LUT Sequence in the file")
+            })?,
+            Windowing::Auto => Voi::Auto,
+            Windowing::Preset(preset) => Voi::Window(WindowLevelTransform::linear(preset.window())),
+            Windowing::Custom(window) => Voi::Window(WindowLevelTransform::linear(*window)),
+        };
+
+        // An INVERSE shape is how MONOCHROME1 images are meant to be shown, not a second inversion
+        let invert = text(obj, tags::PHOTOMETRIC_INTERPRETATION).as_deref() == Some("MONOCHROME1")
+            || text(obj, tags::PRESENTATION_LUT_SHAPE).as_deref() == Some("INVERSE");
+        Ok(Self { rescale, voi, invert })
+    }
+
+    /// 8-bit grey levels of the stored sample values
+    pub fn render(&self, samples: &[f64]) -> Vec<u8> {
+        let values: Vec<f64> = samples.iter().map(|v| self.rescale.apply(*v)).collect();
+        let auto = match self.voi {
+            Voi::Auto => Some(min_max_window(&values)),
+            _ => None,
+        };
+        let voi = |v: f64| match &self.voi {
+            Voi::Window(window) => window.apply(v, 255.),
+            Voi::Lut(lut) => lut.apply(v),
+            Voi::Auto => auto.as_ref().map_or(v, |window| window.apply(v, 255.)),
+        };
+        values
+            .iter()
+            .map(|v| {
+                let grey = voi(*v).round().clamp(0., 255.) as u8;
+                if self.invert { 255 - grey } else { grey }
+            })
+            .collect()
+    }
+}
+
+/// A uniform frame gets a window one value wide, showing it mid-grey
+fn min_max_window(values: &[f64]) -> WindowLevelTransform {
+    let (min, max) = values
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), v| (min.min(*v), max.max(*v)));
+    let (center, width) = if max > min { ((min + max) / 2., max - min) } else { (min, 1.) };
+    WindowLevelTransform::new(VoiLutFunction::LinearExact, WindowLevel { center, width })
+}
+
+impl VoiLut {
+    /// First item of the VOI LUT Sequence
+    fn from_object(obj: &InMemDicomObject) -> Option<Self> {
+        let item = obj.get(tags::VOILUT_SEQUENCE)?.items()?.first()?;
+        let descriptor = item.get(tags::LUT_DESCRIPTOR)?.to_multi_int::<i64>().ok()?;
+        let [entries, first, bits] = descriptor[..] else {
+            return None;
+        };
+        // The first mapped value follows the pixel representation, even when read as US
+        let signed = obj
+            .get(tags::PIXEL_REPRESENTATION)
+            .and_then(|e| e.to_int::<u16>().ok())
+            == Some(1);
+        let first = if signed && first > i16::MAX as i64 { first - 0x10000 } else { first };
+        let entries = if entries == 0 { 0x10000 } else { entries as usize };
+
+        let mut data: Vec<u16> = match item.get(tags::LUT_DATA)?.value().primitive()? {
+            PrimitiveValue::U8(bytes) => bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect(),
+            PrimitiveValue::U16(values) => values.to_vec(),
+            other => other.to_multi_int::<u16>().ok()?,
+        };
+        data.truncate(entries);
+        if data.is_empty() {
+            return None;
+        }
+        Some(Self {
+            first: first as f64,
+            max: ((1u32 << bits.clamp(1, 16)) - 1) as f64,
+            data,
+        })
+    }
+
+    fn apply(&self, value: f64) -> f64 {
+        let index = (value - self.first).clamp(0., (self.data.len() - 1) as f64) as usize;
+        self.data[index] as f64 / self.max * 255.
+    }
+}
+
+/// Modality rescale of a frame, the identity without a Rescale Slope and Intercept
+pub(crate) fn frame_rescale(obj: &InMemDicomObject, frame: u32) -> Rescale {
+    let float = |tag| frame_float(obj, frame, tags::PIXEL_VALUE_TRANSFORMATION_SEQUENCE, tag);
+    Rescale::new(float(tags::RESCALE_SLOPE).unwrap_or(1.), float(tags::RESCALE_INTERCEPT).unwrap_or(0.))
+}
+
+fn frame_float(obj: &InMemDicomObject, frame: u32, sequence: Tag, tag: Tag) -> Option<f64> {
+    frame_element(obj, frame, sequence, tag)
+        .and_then(|e| e.to_multi_float64().ok())
+        .and_then(|v| v.first().copied())
+}
+
+/// Attribute of a frame: from the dataset, else its per-frame or shared functional groups
+pub(crate) fn frame_element(obj: &InMemDicomObject, frame: u32, sequence: Tag, tag: Tag) -> Option<&InMemElement> {
+    let in_group = |group: Tag, index: usize| {
+        obj.get(group)?
+            .items()?
+            .get(index)?
+            .get(sequence)?
+            .items()?
+            .first()?
+            .get(tag)
+    };
+    obj.get(tag)
+        .or_else(|| in_group(tags::PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE, frame as usize))
+        .or_else(|| in_group(tags::SHARED_FUNCTIONAL_GROUPS_SEQUENCE, 0))
+}
+
+pub(crate) fn text(obj: &InMemDicomObject, tag: Tag) -> Option<String> {
+    obj.get(tag)
+        .and_then(|e| e.to_str().ok())
+        .map(|s| s.trim_end_matches(['\0', ' ']).trim().to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsedcm_core::{DataElement, DataSetSequence, VR};
+
+    fn object(photometric: &str, elements: Vec<InMemElement>) -> InMemDicomObject {
+        let mut obj = InMemDicomObject::from_element_iter(elements);
+        obj.put_str(tags::PHOTOMETRIC_INTERPRETATION, VR::CS, photometric);
+        obj
+    }
+
+    fn ds(tag: Tag, value: &str) -> InMemElement {
+        DataElement::new(tag, VR::DS, PrimitiveValue::from(value))
+    }
+
+    /// VOI LUT Sequence of one item, with the data as given
+    fn voi_lut(descriptor: [u16; 3], data: PrimitiveValue, vr: VR) -> InMemElement {
+        let item = InMemDicomObject::from_element_iter([
+            DataElement::new(tags::LUT_DESCRIPTOR, VR::US, PrimitiveValue::U16(descriptor.into_iter().collect())),
+            DataElement::new(tags::LUT_DATA, vr, data),
+        ]);
+        DataElement::new(tags::VOILUT_SEQUENCE, VR::SQ, DataSetSequence::from(vec![item]))
+    }
+
+    fn render(obj: &InMemDicomObject, windowing: Windowing, samples: &[f64]) -> Vec<u8> {
+        Display::new(obj, 0, &windowing).unwrap().render(samples)
+    }
+
+    #[test]
+    fn parse_windowings() {
+        assert_eq!("File".parse::<Windowing>(), Ok(Windowing::File));
+        assert_eq!(" voi-lut".parse::<Windowing>(), Ok(Windowing::VoiLut));
+        assert_eq!("auto".parse::<Windowing>(), Ok(Windowing::Auto));
+        assert_eq!("LUNG".parse::<Windowing>(), Ok(Windowing::Preset(Preset::Lung)));
+        assert_eq!(
+            "-600, 1500".parse::<Windowing>(),
+            Ok(Windowing::Custom(WindowLevel { center: -600., width: 1500. }))
+        );
+        for invalid in ["40", "40,0", "40,0.5", "40,-1", "a,b", "liver"] {
+            assert!(invalid.parse::<Windowing>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn rescale_before_the_window() {
+        let obj = object(
+            "MONOCHROME2",
+            vec![ds(tags::RESCALE_SLOPE, "2"), ds(tags::RESCALE_INTERCEPT, "0"), ds(tags::WINDOW_CENTER, "100"), ds(tags::WINDOW_WIDTH, "101")],
+        );
+        // 50 is 100 once rescaled, the center of the window
+        assert_eq!(render(&obj, Windowing::File, &[0., 50., 100.]), [0, 129, 255]);
+        let custom = Windowing::Custom(WindowLevel { center: 100., width: 101. });
+        assert_eq!(render(&obj, custom, &[0., 50., 100.]), [0, 129, 255]);
+    }
+
+    #[test]
+    fn voi_lut_with_a_signed_first_value() {
+        // -256 stored as US
+        let lut = voi_lut([4, 0xFF00, 8], PrimitiveValue::U16([0, 100, 200, 255].into_iter().collect()), VR::US);
+        let mut obj = object("MONOCHROME2", vec![lut]);
+        obj.put(DataElement::new(tags::PIXEL_REPRESENTATION, VR::US, PrimitiveValue::from(1u16)));
+        assert_eq!(render(&obj, Windowing::VoiLut, &[-300., -256., -255., -254., -253., 1000.]), [0, 0, 100, 200, 255, 255]);
+        // The window of the file comes first
+        obj.put(ds(tags::WINDOW_CENTER, "0"));
+        obj.put(ds(tags::WINDOW_WIDTH, "2"));
+        assert_eq!(render(&obj, Windowing::File, &[-256., 1000.]), [0, 255]);
+    }
+
+    #[test]
+    fn voi_lut_of_65536_entries_read_as_bytes() {
+        // 0 entries standing for 65536, 16-bit values read as OW bytes
+        let bytes: Vec<u8> = (0..=u16::MAX).flat_map(|v| v.to_le_bytes()).collect();
+        let obj = object("MONOCHROME2", vec![voi_lut([0, 0, 16], PrimitiveValue::from(bytes), VR::OW)]);
+        assert_eq!(render(&obj, Windowing::VoiLut, &[0., 32768., 65535.]), [0, 128, 255]);
+    }
+
+    #[test]
+    fn missing_voi_lut() {
+        let obj = object("MONOCHROME2", Vec::new());
+        assert!(Display::new(&obj, 0, &Windowing::VoiLut).is_err());
+        // Falls back to min/max
+        assert_eq!(render(&obj, Windowing::File, &[10., 20.]), [0, 255]);
+    }
+
+    #[test]
+    fn invert_once() {
+        let window = Windowing::Custom(WindowLevel { center: 128., width: 256. });
+        let mut obj = object("MONOCHROME2", Vec::new());
+        assert_eq!(render(&obj, window, &[0., 255.]), [0, 255]);
+        obj.put_str(tags::PRESENTATION_LUT_SHAPE, VR::CS, "INVERSE");
+        assert_eq!(render(&obj, window, &[0., 255.]), [255, 0]);
+        let mut obj = object("MONOCHROME1", Vec::new());
+        assert_eq!(render(&obj, window, &[0., 255.]), [255, 0]);
+        obj.put_str(tags::PRESENTATION_LUT_SHAPE, VR::CS, "INVERSE");
+        assert_eq!(render(&obj, window, &[0., 255.]), [255, 0]);
+    }
+
+    #[test]
+    fn auto_window_of_a_uniform_frame() {
+        let obj = object("MONOCHROME2", Vec::new());
+        assert_eq!(render(&obj, Windowing::Auto, &[-1024.; 4]), [128; 4]);
+        assert_eq!(render(&obj, Windowing::Auto, &[-1024., 0., 1024.]), [0, 128, 255]);
+    }
+}
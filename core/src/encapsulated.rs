@@ -0,0 +1,123 @@
+use dicom_dictionary_std::tags;
+
+use crate::{DicomValue, InMemDicomObject, PrimitiveValue, PulseError, PulseErrorKind, Result};
+
+/// Start of a JPEG 2000 codestream, SOC then SIZ marker
+const CODESTREAM_START: [u8; 4] = [0xFF, 0x4F, 0xFF, 0x51];
+
+/// Bytes of the Item tag and length before each fragment
+const ITEM_HEADER: u64 = 8;
+
+/// JPEG 2000 codestream of every frame of an encapsulated object, see `frame_fragments`
+pub fn frame_codestreams(obj: &InMemDicomObject) -> Result<Vec<Vec<u8>>> {
+    frame_fragments(obj, |fragment| fragment.starts_with(&CODESTREAM_START))
+}
+
+/// Compressed bytes of every frame of an encapsulated object.
+/// Fragments are split into frames by the Extended Offset Table, else the Basic Offset Table,
+/// else one fragment per frame, else at each fragment `is_frame_start` of the syntax
+pub fn frame_fragments(obj: &InMemDicomObject, is_frame_start: impl Fn(&[u8]) -> bool) -> Result<Vec<Vec<u8>>> {
+    let unsupported = |message: &str| PulseError::new(PulseErrorKind::UnsupportedPixelData, message);
+    let pixel_data = obj
+        .get(tags::PIXEL_DATA)
+        .ok_or_else(|| unsupported("No Pixel Data in the file"))?;
+    let DicomValue::PixelSequence(sequence) = pixel_data.value() else {
+        return Err(unsupported("Pixel Data isn't encapsulated"));
+    };
+    let fragments = sequence.fragments();
+    let count = obj
+        .get(tags::NUMBER_OF_FRAMES)
+        .and_then(|e| e.to_int::<u32>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(1) as usize;
+
+    let offsets: Vec<u64> = match extended_offset_table(obj) {
+        Some(offsets) => offsets,
+        None => sequence.offset_table().iter().map(|o| *o as u64).collect(),
+    };
+    let starts = if !offsets.is_empty() {
+        fragment_starts(fragments, &offsets)
+            .ok_or_else(|| unsupported("Offset table doesn't match the fragments of the Pixel Data"))?
+    } else if count == 1 {
+        vec![0]
+    } else if fragments.len() == count {
+        (0..count).collect()
+    } else {
+        fragments
+            .iter()
+            .enumerate()
+            .filter(|(_, fragment)| is_frame_start(fragment))
+            .map(|(index, _)| index)
+            .collect()
+    };
+    if starts.first() != Some(&0) || starts.len() != count {
+        return Err(unsupported(&format!(
+            "Found {} frame(s) in the Pixel Data, expected {}",
+            starts.len(),
+            count
+        )));
+    }
+
+    let ends = starts.iter().skip(1).copied().chain([fragments.len()]);
+    Ok(starts.iter().zip(ends).map(|(start, end)| fragments[*start..end].concat()).collect())
+}
+
+/// Offsets, from the first fragment, of the frames of the Extended Offset Table
+fn extended_offset_table(obj: &InMemDicomObject) -> Option<Vec<u64>> {
+    let offsets: Vec<u64> = match obj.get(tags::EXTENDED_OFFSET_TABLE)?.value().primitive()? {
+        PrimitiveValue::U8(bytes) => bytes
+            .chunks_exact(8)
+            .map(|b| u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+            .collect(),
+        other => other.to_multi_int::<u64>().ok()?,
+    };
+    (!offsets.is_empty()).then_some(offsets)
+}
+
+/// Index of the fragment at each offset, counted from the Item tag of the first fragment
+fn fragment_starts(fragments: &[Vec<u8>], offsets: &[u64]) -> Option<Vec<usize>> {
+    let mut position = 0;
+    let positions: Vec<u64> = fragments
+        .iter()
+        .map(|fragment| {
+            let start = position;
+            position += ITEM_HEADER + fragment.len() as u64;
+            start
+        })
+        .collect();
+    offsets.iter().map(|offset| positions.binary_search(offset).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataElement, Length, PixelFragmentSequence, VR};
+
+    fn encapsulated(frames: u32, offsets: Vec<u32>, fragments: Vec<Vec<u8>>) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter([
+            DataElement::new(tags::NUMBER_OF_FRAMES, VR::IS, PrimitiveValue::from(frames.to_string())),
+            DataElement::new_with_len(
+                tags::PIXEL_DATA,
+                VR::OB,
+                Length::UNDEFINED,
+                PixelFragmentSequence::new(offsets, fragments),
+            ),
+        ])
+    }
+
+    #[test]
+    fn split_by_offset_table() {
+        let obj = encapsulated(2, vec![0, 20], vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(frame_codestreams(&obj).unwrap(), [vec![1, 2, 3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn split_at_frame_starts() {
+        let fragments = vec![vec![0xFF, 1], vec![2, 3], vec![0xFF, 4], vec![5, 6]];
+        let obj = encapsulated(2, vec![], fragments);
+        let frames = frame_fragments(&obj, |fragment| fragment[0] == 0xFF).unwrap();
+        assert_eq!(frames, [vec![0xFF, 1, 2, 3], vec![0xFF, 4, 5, 6]]);
+        // Not a JPEG 2000 codestream
+        assert!(frame_codestreams(&obj).is_err());
+    }
+}
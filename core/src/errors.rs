@@ -9,6 +9,7 @@ pub enum DicomError {
     Access(dicom_object::AtAccessError),
     Write(dicom_object::WriteError),
     PixelData(dicom_pixeldata::Error),
+    Transcode(dicom_pixeldata::TranscodeError),
 }
 
 impl Display for DicomError {
@@ -18,6 +19,7 @@ impl Display for DicomError {
             Self::Access(e) => write!(f, "Access: {}", e ),
             Self::Write(e) => write!(f, "Write: {}", e ), 
             Self::PixelData(e) => write!(f, "Read: {}", e ),
+            Self::Transcode(e) => write!(f, "Transcode: {}", e ),
         }
     }
 }
@@ -29,6 +31,7 @@ impl Error for DicomError {
             Self::Access(s) => Some(s),
             Self::Write(s) => Some(s),
             Self::PixelData(s) => Some(s),
+            Self::Transcode(s) => Some(s),
             // _ => None,
         }
     }
@@ -89,6 +92,23 @@ impl Error for PulseErrorKind {
         }
     }
 }
+impl PulseErrorKind {
+    /// Name of the kind, to group errors by
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::IO(_) => "IO",
+            Self::SystemTime(_) => "SystemTime",
+            Self::Dicom(_) => "Dicom",
+            Self::Threading(_) => "Threading",
+            Self::ThreadPoison(_) => "ThreadPoison",
+            Self::ImageError(_) => "ImageError",
+            Self::CodecError(_) => "CodecError",
+            Self::CSV(_) => "CSV",
+            Self::UnsupportedPixelData => "UnsupportedPixelData",
+            Self::UnsupportedComponent => "UnsupportedComponent",
+        }
+    }
+}
 // ======== END MAIN ERROR KIND ========= 
 
 
@@ -106,6 +126,10 @@ impl PulseError {
             message: message.into(),
         }
     }
+
+    pub fn kind(&self) -> &PulseErrorKind {
+        &self.kind
+    }
 }
 
 impl Display for PulseError {
@@ -204,5 +228,10 @@ impl From<dicom_pixeldata::Error> for PulseError {
     }
 }
 
-
-
+impl From<dicom_pixeldata::TranscodeError> for PulseError {
+    fn from(e: dicom_pixeldata::TranscodeError) -> Self { Self { 
+            kind: PulseErrorKind::Dicom(DicomError::Transcode(e)), 
+            message: "DICOM transcode error".to_string(), 
+        }
+    }
+}
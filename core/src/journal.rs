@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs::{canonicalize, create_dir_all, metadata, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use csv::{ReaderBuilder, Writer, WriterBuilder};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{File, Result, RunSummary};
+
+/// Inputs completed by a run, appended as they're done so that an interrupted run can
+/// be resumed. Each one is recorded as left by the run: an input overwritten in place
+/// is recorded with its new content
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    done: HashMap<PathBuf, Entry>,
+    writer: Mutex<Writer<File>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    input: PathBuf,
+    size: u64,
+    mtime: u128, // Nanoseconds since the epoch
+    sha256: String,
+}
+
+impl Journal {
+    /// Journal of `command` in the output directory, or beside the output file.
+    /// Without `resume` a previous journal is started over
+    pub fn for_output(output: &Path, command: &str, resume: bool) -> Result<Self> {
+        let dir = if output.is_dir() {
+            output
+        } else {
+            output.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."))
+        };
+        Self::open(dir.join(format!(".pulsedcm-{}-journal.csv", command)), resume)
+    }
+
+    /// Journal of `command` run on `input`, in the user cache directory: runs writing into
+    /// their input tree leave nothing behind in it
+    pub fn in_cache(input: &Path, command: &str, resume: bool) -> Result<Self> {
+        let dir = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .unwrap_or_else(env::temp_dir)
+            .join("pulsedcm");
+        create_dir_all(&dir)?;
+        let digest = Sha256::digest(key(input).as_os_str().as_encoded_bytes());
+        let id: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+        Self::open(dir.join(format!("{}-{}-journal.csv", command, id)), resume)
+    }
+
+    pub fn open(path: PathBuf, resume: bool) -> Result<Self> {
+        let mut done = HashMap::new();
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(&path)?;
+        if resume {
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            // A line cut by the interruption is ignored, and ended before appending
+            if !content.is_empty() && !content.ends_with(b"\n") {
+                file.write_all(b"\n")?;
+            }
+            let mut reader = ReaderBuilder::new().from_reader(content.as_slice());
+            for entry in reader.deserialize::<Entry>().filter_map(|e| e.ok()) {
+                done.insert(entry.input.clone(), entry);
+            }
+        } else {
+            file.set_len(0)?;
+        }
+
+        let fresh = file.metadata()?.len() == 0;
+        let mut writer = WriterBuilder::new().has_headers(false).from_writer(file);
+        if fresh {
+            writer.write_record(["input", "size", "mtime", "sha256"])?;
+            writer.flush()?;
+        }
+        Ok(Self {
+            path,
+            done,
+            writer: Mutex::new(writer),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Inputs left to process. A recorded input is done when its size and modification
+    /// time are unchanged, its content being hashed only when the time alone changed
+    pub fn pending(&self, files: Vec<PathBuf>, summary: &RunSummary) -> Result<Vec<PathBuf>> {
+        if self.done.is_empty() {
+            return Ok(files);
+        }
+        let total = files.len();
+        let pending: Vec<PathBuf> = files
+            .into_par_iter()
+            .map(|file| -> Result<Option<PathBuf>> { Ok((!self.is_done(&file)?).then_some(file)) })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        summary.skip(total - pending.len());
+        println!(
+            "Resuming from {}: {} file(s) already done, {} left",
+            self.path.display(),
+            total - pending.len(),
+            pending.len()
+        );
+        Ok(pending)
+    }
+
+    /// Whether `input` was recorded and is unchanged since, see `pending`
+    pub fn is_done(&self, input: &Path) -> Result<bool> {
+        let Some(entry) = self.done.get(&key(input)) else {
+            return Ok(false);
+        };
+        let (size, mtime) = stat(input)?;
+        if entry.size != size {
+            return Ok(false);
+        }
+        Ok(entry.mtime == mtime || entry.sha256 == hash(input)?)
+    }
+
+    /// Record an input once its processing succeeded
+    pub fn complete(&self, input: &Path) -> Result<()> {
+        let (size, mtime) = stat(input)?;
+        let entry = Entry {
+            input: key(input),
+            size,
+            mtime,
+            sha256: hash(input)?,
+        };
+        let mut writer = self.writer.lock()?;
+        writer.serialize(entry)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Same input whatever the path given to the run
+fn key(input: &Path) -> PathBuf {
+    canonicalize(input).unwrap_or_else(|_| input.to_path_buf())
+}
+
+fn stat(input: &Path) -> Result<(u64, u128)> {
+    let meta = metadata(input)?;
+    let mtime = meta.modified()?.duration_since(UNIX_EPOCH)?.as_nanos();
+    Ok((meta.len(), mtime))
+}
+
+fn hash(input: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(input)?, &mut hasher)?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn resume_completed_inputs() {
+        let dir = env::temp_dir().join(format!("pulsedcm-journal-{}", std::process::id()));
+        create_dir_all(&dir).unwrap();
+        let [same, touched, changed, new] = ["same", "touched", "changed", "new"].map(|name| dir.join(name));
+        for file in [&same, &touched, &changed, &new] {
+            write(file, b"content").unwrap();
+        }
+
+        let journal = Journal::open(dir.join("journal.csv"), false).unwrap();
+        for file in [&same, &touched, &changed] {
+            journal.complete(file).unwrap();
+        }
+        drop(journal);
+
+        // Same size and content, another time
+        let later = SystemTime::now() + Duration::from_secs(60);
+        File::options().write(true).open(&touched).unwrap().set_modified(later).unwrap();
+        write(&changed, b"CONTENT").unwrap();
+        File::options().write(true).open(&changed).unwrap().set_modified(later).unwrap();
+
+        let journal = Journal::open(dir.join("journal.csv"), true).unwrap();
+        let files = vec![same, touched, changed.clone(), new.clone()];
+        assert_eq!(journal.pending(files, &RunSummary::new()).unwrap(), [changed, new]);
+        drop(journal);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
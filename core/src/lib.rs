@@ -1,18 +1,27 @@
 use serde::Serialize;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::PathBuf;
 
 pub use rayon;
 pub use rayon::prelude::*;
 
-pub use dicom_core::{DataDictionary, PrimitiveValue, DicomValue, VR};
-pub use dicom_core::value::Value;
+pub use dicom_core::{DataDictionary, DataElement, PrimitiveValue, DicomValue, VR};
+pub use dicom_core::value::{DataSetSequence, PixelFragmentSequence, Value};
+pub use dicom_core::Length;
 pub use dicom_dictionary_std;
 pub use dicom_dictionary_std::StandardDataDictionary;
 pub use dicom_object::{open_file, FileDicomObject, InMemDicomObject, OpenFileOptions, Tag};
-pub use dicom_pixeldata::PixelDecoder;
+pub use dicom_object::mem::InMemElement;
+pub use dicom_object::meta::{FileMetaTable, FileMetaTableBuilder};
+pub use dicom_pixeldata::{DecodedPixelData, PixelDecoder, PlanarConfiguration, Transcode};
+pub use dicom_pixeldata::{
+    ConvertOptions, ModalityLutOption, Rescale, VoiLutFunction, WindowLevel, WindowLevelTransform,
+};
+pub use dicom_encoding::TransferSyntaxIndex;
+pub use dicom_encoding::adapters::EncodeOptions;
+pub use dicom_transfer_syntax_registry::{self, TransferSyntaxRegistry};
 
-pub use std::fs::{File, create_dir};
+pub use std::fs::{File, create_dir, create_dir_all};
 pub use std::path::Path;
 pub use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -21,6 +30,15 @@ use walkdir::{self,  WalkDir};
 mod errors;
 pub use errors::{PulseError, PulseErrorKind};
 
+mod encapsulated;
+pub use encapsulated::{frame_codestreams, frame_fragments};
+
+mod journal;
+pub use journal::Journal;
+
+mod summary;
+pub use summary::RunSummary;
+
 pub type Result<T> = std::result::Result<T, PulseError>;
 
 pub fn list_all_files(user_path: &str) -> Result<Vec<String>> {
@@ -75,6 +93,24 @@ pub fn collect_dicom_files(user_path: &str) -> Result<Vec<PathBuf>> {
     Ok(res)
 }
 
+/// Path of `input` in the input tree mirrored under `output_root`, a single input file
+/// being its own root. Fails for an input outside of `input_root`
+pub fn mirror_path(input_root: &Path, output_root: &Path, input: &Path) -> Result<PathBuf> {
+    if input_root.is_file() {
+        if output_root == input_root {
+            return Ok(output_root.to_path_buf());
+        }
+        return Ok(output_root.join(input.file_name().unwrap_or_default()));
+    }
+    let relative = input.strip_prefix(input_root).map_err(|_| {
+        errors::PulseError::new(
+            errors::PulseErrorKind::IO(io::Error::new(io::ErrorKind::InvalidInput, "Invalid output path")),
+            format!("{} is not under the input path {}", input.display(), input_root.display()),
+        )
+    })?;
+    Ok(output_root.join(relative))
+}
+
 #[derive(Serialize)]
 pub struct SerializableDicomEntry {
     pub filename: String,
@@ -100,6 +136,81 @@ pub fn jobs_handling(jobs: Option<usize>, max_file: usize) -> usize {
     }
 }
 
+/// How the questions of a run are answered, once and before any file is processed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Confirmation {
+    #[default]
+    Ask,       // Ask on the terminal, fail without one
+    Yes,       // --yes: answer yes to every question
+    NoClobber, // --no-clobber: never overwrite an existing file
+}
+
+impl Confirmation {
+    /// Answer to a question allowing to overwrite files
+    pub fn confirm(&self, question: &str) -> Result<bool> {
+        match self {
+            Self::Yes => Ok(true),
+            Self::NoClobber => Ok(false),
+            Self::Ask if io::stdin().is_terminal() => Ok(ask_yes_no(question)),
+            Self::Ask => Err(PulseError::new(
+                errors::PulseErrorKind::IO(io::Error::other("No terminal to ask for confirmation")),
+                format!("{} Use --yes or --no-clobber to run without a terminal", question.trim_start_matches("? ")),
+            )),
+        }
+    }
+
+    /// Fails for an existing file with `--no-clobber`
+    pub fn check_destination(&self, path: &Path) -> Result<()> {
+        if *self == Self::NoClobber && path.exists() {
+            return Err(PulseError::new(
+                errors::PulseErrorKind::IO(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    "File not overwritten",
+                )),
+                format!("{} already exists and --no-clobber is set", path.display()),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Overwriting the input files, answered once for the whole run before the workers start.
+    /// Fails with `--no-clobber`, the inputs are all skipped when declined
+    pub fn overwrite_inputs(&self, count: usize, summary: &RunSummary) -> Result<bool> {
+        if count == 0 {
+            return Ok(true);
+        }
+        if *self == Self::NoClobber {
+            return Err(PulseError::new(
+                errors::PulseErrorKind::IO(io::Error::new(io::ErrorKind::AlreadyExists, "Files not overwritten")),
+                "No --out given, the input files would be overwritten and --no-clobber is set",
+            ));
+        }
+        if !self.confirm("? No --out specified confirm to overwrite actual files")? {
+            println!("Stopping...");
+            summary.skip(count);
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Create the output folder if missing, creating a folder never clobbering anything
+    pub fn prepare_output_dir(&self, path: &Path) -> Result<()> {
+        if path.exists() {
+            return Ok(());
+        }
+        if *self == Self::NoClobber
+            || self.confirm("Output folder doesn't exist, would you like to create it?")?
+        {
+            create_dir_all(path)?;
+            return Ok(());
+        }
+        Err(PulseError::new(
+            errors::PulseErrorKind::IO(io::Error::other("Output folder not created, hence terminate")),
+            "I/O",
+        ))
+    }
+}
+
 pub fn ask_yes_no(question: &str) -> bool {
     print!("{} Y/N: ", question);
     let _ = io::stdout().flush();
@@ -169,7 +280,7 @@ pub fn print_colorize(tag: Tag, vr: &str, value: &str, name: &str, out_string: &
     }
 }
 
-fn is_phi_tag(tag: Tag) -> bool {
+pub fn is_phi_tag(tag: Tag) -> bool {
     matches!(
         tag,
         Tag(0x0010, 0x0010) // Patient's Name
@@ -190,7 +301,7 @@ fn is_phi_tag(tag: Tag) -> bool {
     )
 }
 
-fn is_warning_tag(tag: Tag) -> bool {
+pub fn is_warning_tag(tag: Tag) -> bool {
     matches!(
         tag,
         Tag(0x0008, 0x0050) // Accession Number
@@ -211,20 +322,16 @@ fn is_warning_tag(tag: Tag) -> bool {
 }
 
 pub fn output_handling(input_path: &PathBuf, output_path: &mut PathBuf) -> Result<()>{
-    // Check if output_path exists (and create a folder if not )
+    // The output folder is created before the run, see `Confirmation::prepare_output_dir`
     if !output_path.exists() {
-        if ask_yes_no("Output folder doesn't exist, would you like to create it?") {
-            create_dir(output_path.as_path()).unwrap();
-        } else {
-            return Err(PulseError::new(
-                errors::PulseErrorKind::IO(
-                    io::Error::new(
-                        io::ErrorKind::Other, "Output folder not created, hence terminate"
-                        )
-                    ), 
-                "I/O"
-                ));
-        }
+        return Err(PulseError::new(
+            errors::PulseErrorKind::IO(
+                io::Error::new(
+                    io::ErrorKind::NotFound, "Output folder doesn't exist"
+                    )
+                ), 
+            "I/O"
+            ));
     } 
     if input_path == output_path {
         let filename = input_path.file_name().unwrap();
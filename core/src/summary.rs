@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+use crate::{File, PulseError, Result};
+
+/// Outcome of every input of a run, printed at its end
+#[derive(Debug, Default)]
+pub struct RunSummary {
+    processed: AtomicUsize,
+    skipped: AtomicUsize,
+    failures: Mutex<Vec<Failure>>,
+    // Error stopping the run itself, not tied to an input
+    aborted: Mutex<Option<Failure>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Failure {
+    input: Option<PathBuf>,
+    category: &'static str,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    processed: usize,
+    skipped: usize,
+    failed: usize,
+    categories: BTreeMap<&'static str, usize>,
+    aborted: Option<&'a Failure>,
+    failures: &'a [Failure],
+}
+
+impl RunSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inputs left aside, e.g. completed by a previous run
+    pub fn skip(&self, count: usize) {
+        self.skipped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Outcome of an input, a failure being reported right away
+    pub fn record(&self, input: &Path, outcome: Result<()>) {
+        match outcome {
+            Ok(()) => {
+                self.processed.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => {
+                eprintln!("Can't process {} : {}", input.display(), e);
+                self.failures
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .push(Failure::new(Some(input), &e));
+            }
+        }
+    }
+
+    pub fn abort(&self, error: &PulseError) {
+        *self.aborted.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Failure::new(None, error));
+    }
+
+    /// An input failed, or the run didn't complete
+    pub fn failed(&self) -> bool {
+        self.with_report(|report| report.failed > 0 || report.aborted.is_some())
+    }
+
+    pub fn print(&self) {
+        self.with_report(|report| {
+            println!(
+                "\x1b[1m>> \x1b[0m{} processed, {} skipped, {} failed",
+                report.processed, report.skipped, report.failed
+            );
+            for (category, count) in &report.categories {
+                println!("   {:<20} {}", category, count);
+            }
+            if let Some(aborted) = report.aborted {
+                eprintln!("\x1b[1;31mRun stopped\x1b[0m: {}", aborted.error);
+            }
+        })
+    }
+
+    /// JSON report, every failure included
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = File::create(path)?;
+        self.with_report(|report| serde_json::to_writer_pretty(file, report))
+            .map_err(|e| PulseError::from(std::io::Error::from(e)))
+    }
+
+    fn with_report<T>(&self, f: impl FnOnce(&Report) -> T) -> T {
+        let failures = self.failures.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let aborted = self.aborted.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut categories = BTreeMap::new();
+        for failure in failures.iter() {
+            *categories.entry(failure.category).or_insert(0) += 1;
+        }
+        f(&Report {
+            processed: self.processed.load(Ordering::Relaxed),
+            skipped: self.skipped.load(Ordering::Relaxed),
+            failed: failures.len(),
+            categories,
+            aborted: aborted.as_ref(),
+            failures: &failures,
+        })
+    }
+}
+
+impl Failure {
+    fn new(input: Option<&Path>, error: &PulseError) -> Self {
+        Self {
+            input: input.map(Path::to_path_buf),
+            category: error.kind().category(),
+            error: error.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PulseErrorKind;
+
+    fn io_error() -> Result<()> {
+        Err(std::io::Error::new(std::io::ErrorKind::NotFound, "missing").into())
+    }
+
+    fn pixel_error() -> Result<()> {
+        Err(PulseError::new(PulseErrorKind::UnsupportedPixelData, "No pixel data"))
+    }
+
+    #[test]
+    fn count_the_outcomes() {
+        let summary = RunSummary::new();
+        assert!(!summary.failed());
+        summary.record(Path::new("a.dcm"), Ok(()));
+        summary.record(Path::new("b.dcm"), Ok(()));
+        summary.skip(3);
+        assert!(!summary.failed());
+
+        summary.record(Path::new("c.dcm"), io_error());
+        summary.record(Path::new("d.dcm"), pixel_error());
+        summary.record(Path::new("e.dcm"), io_error());
+        assert!(summary.failed());
+        summary.with_report(|report| {
+            assert_eq!((report.processed, report.skipped, report.failed), (2, 3, 3));
+            assert_eq!(report.categories, BTreeMap::from([("IO", 2), ("UnsupportedPixelData", 1)]));
+            assert!(report.aborted.is_none());
+        });
+    }
+
+    #[test]
+    fn aborted_runs_fail() {
+        let summary = RunSummary::new();
+        summary.record(Path::new("a.dcm"), Ok(()));
+        summary.abort(&PulseError::new(PulseErrorKind::UnsupportedComponent, "Stopped"));
+        assert!(summary.failed());
+    }
+
+    #[test]
+    fn write_the_report() {
+        let summary = RunSummary::new();
+        summary.record(Path::new("a.dcm"), Ok(()));
+        summary.skip(1);
+        summary.record(Path::new("b.dcm"), pixel_error());
+
+        let dir = std::env::temp_dir().join(format!("pulsedcm-summary-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("summary.json");
+        summary.write(&path).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "processed": 1,
+                "skipped": 1,
+                "failed": 1,
+                "categories": { "UnsupportedPixelData": 1 },
+                "aborted": null,
+                "failures": [{
+                    "input": "b.dcm",
+                    "category": "UnsupportedPixelData",
+                    "error": pixel_error().unwrap_err().to_string(),
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,814 @@
+/*!
+
+# Rust bindings to OpenJPEG
+
+Supports loading JPEG2000 images into `image::DynamicImage`.
+
+Forked from https://framagit.org/leoschwarz/jpeg2000-rust before its GPL-v3 relicensing, with some additional features:
+
+* Specify decoding area and quality layers in addition to reduction factor
+* Improved OpenJPEG -> DynamicImage loading process
+* Get basic metadata from JPEG2000 headings
+* Docs (albeit minimal ones)
+* Encode 8 to 16-bit samples into J2K codestreams, lossless or lossy
+
+This library brings its own libopenjpeg, which is statically linked. If you just need raw FFI bindings, see
+[openjpeg2-sys](https://crates.io/crates/openjpeg2-sys) or [openjpeg-sys](https://crates.io/crates/openjpeg-sys).
+
+
+## Usage
+
+```rust,no_run
+fn main() {
+    let bytes = include_bytes!("./rust-logo-512x512-blk.jp2");
+
+    let jp2k::Image(img) = jp2k::Image::from_bytes(
+        bytes,
+        jp2k::Codec::JP2,
+        Some(jp2k::DecodeParams::default().with_decoding_area(0, 0, 256, 256))
+    )
+    .unwrap();
+
+    let mut output = std::path::Path::new("examples/output/result.png");
+    let _ = img.save(&mut output);
+}
+```
+
+## Original warnings and license statement
+
+### Warning
+Please be advised that using C code means this crate is likely vulnerable to various memory exploits, e.g. see [http://cve.mitre.org/cgi-bin/cvename.cgi?name=CVE-2016-8332](CVE-2016-8332) for an actual example from the past.
+
+As soon as someone writes an efficient JPEG2000 decoder in pure Rust you should probably switch over to that.
+
+### License
+You can use the Rust code in the directories `src` and `openjp2-sys/src` under the terms of either the MIT license (`LICENSE-MIT` file) or the Apache license (`LICENSE-APACHE` file). Please note that this will link statically to OpenJPEG, which has its own license which you can find at `openjpeg-sys/libopenjpeg/LICENSE` (you might have to check out the git submodule first).
+*/
+
+pub mod err {
+    #[derive(Debug)]
+    pub enum Error {
+        NulError(std::ffi::NulError),
+        Io(std::io::Error),
+        Boxed(Box<dyn std::error::Error + Send + Sync>),
+    }
+
+    impl Error {
+        pub fn boxed<E: Into<Box<dyn std::error::Error + 'static + Send + Sync>>>(e: E) -> Self {
+            Error::Boxed(e.into())
+        }
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            use Error::*;
+            match self {
+                NulError(ref e) => {
+                    write!(f, "{}", e)?;
+                }
+                Io(ref e) => {
+                    write!(f, "{}", e)?;
+                }
+                Boxed(ref e) => {
+                    write!(f, "{}", e)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl From<std::ffi::NulError> for Error {
+        fn from(t: std::ffi::NulError) -> Self {
+            Error::NulError(t)
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    pub type Result<T> = std::result::Result<T, Error>;
+}
+
+#[cfg(feature = "docs-rs")]
+#[path = "ffi.ref.rs"]
+mod ffi;
+
+#[cfg(not(feature = "docs-rs"))]
+mod ffi;
+
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+
+pub use ffi::{CODEC_FORMAT, COLOR_SPACE};
+
+struct InnerDecodeParams(ffi::opj_dparameters);
+
+impl Default for InnerDecodeParams {
+    fn default() -> Self {
+        let mut new = unsafe { std::mem::zeroed::<ffi::opj_dparameters>() };
+        unsafe {
+            ffi::opj_set_default_decoder_parameters(&mut new as *mut _);
+        }
+        InnerDecodeParams(new)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct DecodingArea {
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+}
+
+/// Parameters used to decode JPEG2000 image
+#[derive(Debug, Clone, Default)]
+pub struct DecodeParams {
+    default_color_space: Option<COLOR_SPACE>,
+    reduce_factor: Option<u32>,
+    decoding_area: Option<DecodingArea>,
+    quality_layers: Option<u32>,
+    num_threads: Option<i32>,
+}
+
+impl DecodeParams {
+    /// Used when the library cannot determine color space
+    pub fn with_default_colorspace(mut self, color_space: COLOR_SPACE) -> Self {
+        self.default_color_space = Some(color_space);
+        self
+    }
+
+    /// Image will be "scaled" to dim / (2 ^ reduce_factor)
+    pub fn with_reduce_factor(mut self, reduce_factor: u32) -> Self {
+        self.reduce_factor = Some(reduce_factor);
+        self
+    }
+
+    pub fn with_num_threads(mut self, num: i32) -> Self {
+        self.num_threads = Some(num);
+        self
+    }
+
+    /// Image will be "cropped" to the specified decoding area, with width = x1 - x0 and height y1 - y0
+    pub fn with_decoding_area(mut self, x0: i32, y0: i32, x1: i32, y1: i32) -> Self {
+        self.decoding_area = Some(DecodingArea { x0, y0, x1, y1 });
+        self
+    }
+
+    /// Will only use the specified number of quality layers
+    pub fn with_quality_layers(mut self, quality_layers: u32) -> Self {
+        self.quality_layers = Some(quality_layers);
+        self
+    }
+}
+
+/// Compression of the encoded codestream
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Compression {
+    /// Reversible 5/3 wavelet
+    #[default]
+    Lossless,
+    /// Irreversible 9/7 wavelet at a compression ratio, e.g. `10.0` for 10:1
+    Rate(f32),
+    /// Irreversible 9/7 wavelet at a PSNR target, in dB
+    Quality(f32),
+}
+
+/// Parameters used to encode JPEG2000 image
+#[derive(Debug, Clone, Default)]
+pub struct EncodeParams {
+    compression: Compression,
+    num_resolutions: Option<u32>,
+    color_transform: bool,
+}
+
+impl EncodeParams {
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Number of wavelet resolutions, 6 by default, lowered to what the image size allows
+    pub fn with_num_resolutions(mut self, num_resolutions: u32) -> Self {
+        self.num_resolutions = Some(num_resolutions);
+        self
+    }
+
+    /// Apply the multiple component transform to 3 components images (RCT if lossless, ICT otherwise),
+    /// left as RGB by default
+    pub fn with_color_transform(mut self, color_transform: bool) -> Self {
+        self.color_transform = color_transform;
+        self
+    }
+}
+
+/// Stream of openjpeg, borrowing the buffer it reads from or writes into for `'a`
+pub struct Stream<'a>(*mut ffi::opj_stream_t, PhantomData<&'a mut [u8]>);
+
+impl Drop for Stream<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::opj_stream_destroy(self.0);
+        }
+    }
+}
+
+impl<'a> Stream<'a> {
+    pub fn from_file<T: Into<Vec<u8>>>(file_name: T) -> err::Result<Self> {
+        let file_name = CString::new(file_name)?;
+        let ptr = unsafe { ffi::opj_stream_create_default_file_stream(file_name.as_ptr(), 1) };
+        Ok(Stream(ptr, PhantomData))
+    }
+
+    pub fn from_bytes(buf: &'a [u8]) -> err::Result<Self> {
+        #[derive(Debug)]
+        struct SliceWithOffset<'a> {
+            buf: &'a [u8],
+            offset: usize,
+        }
+
+        unsafe extern "C" fn opj_stream_free_user_data_fn(p_user_data: *mut c_void) {
+            drop(Box::from_raw(p_user_data as *mut SliceWithOffset))
+        }
+
+        unsafe extern "C" fn opj_stream_read_fn(
+            p_buffer: *mut c_void,
+            p_nb_bytes: usize,
+            p_user_data: *mut c_void,
+        ) -> usize {
+            if p_buffer.is_null() {
+                return 0;
+            }
+
+            let user_data = p_user_data as *mut SliceWithOffset;
+
+            let len = (&*user_data).buf.len();
+
+            let offset = (&*user_data).offset;
+
+            let bytes_left = len - offset;
+
+            let bytes_read = std::cmp::min(bytes_left, p_nb_bytes);
+
+            let slice = &(&*user_data).buf[offset..offset + bytes_read];
+
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), p_buffer as *mut u8, bytes_read);
+
+            (*user_data).offset += bytes_read;
+
+            bytes_read
+        }
+
+        let buf_len = buf.len();
+        let user_data = Box::new(SliceWithOffset { buf, offset: 0 });
+
+        let ptr = unsafe {
+            let jp2_stream = ffi::opj_stream_default_create(1);
+            ffi::opj_stream_set_read_function(jp2_stream, Some(opj_stream_read_fn));
+            ffi::opj_stream_set_user_data_length(jp2_stream, buf_len as u64);
+            ffi::opj_stream_set_user_data(
+                jp2_stream,
+                Box::into_raw(user_data) as *mut c_void,
+                Some(opj_stream_free_user_data_fn),
+            );
+            jp2_stream
+        };
+
+        Ok(Stream(ptr, PhantomData))
+    }
+
+    /// Output stream writing into `buf`, borrowed for as long as the stream lives
+    fn to_vec(buf: &'a mut Vec<u8>) -> Self {
+        #[derive(Debug)]
+        struct VecWithOffset {
+            buf: *mut Vec<u8>,
+            offset: usize,
+        }
+
+        unsafe extern "C" fn opj_stream_free_user_data_fn(p_user_data: *mut c_void) {
+            drop(Box::from_raw(p_user_data as *mut VecWithOffset))
+        }
+
+        unsafe extern "C" fn opj_stream_write_fn(
+            p_buffer: *mut c_void,
+            p_nb_bytes: usize,
+            p_user_data: *mut c_void,
+        ) -> usize {
+            let user_data = &mut *(p_user_data as *mut VecWithOffset);
+            let buf = &mut *user_data.buf;
+
+            let end = user_data.offset + p_nb_bytes;
+            if buf.len() < end {
+                buf.resize(end, 0);
+            }
+
+            let slice = std::slice::from_raw_parts(p_buffer as *const u8, p_nb_bytes);
+            buf[user_data.offset..end].copy_from_slice(slice);
+
+            user_data.offset = end;
+
+            p_nb_bytes
+        }
+
+        unsafe extern "C" fn opj_stream_skip_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i64 {
+            let user_data = &mut *(p_user_data as *mut VecWithOffset);
+            user_data.offset = (user_data.offset as i64 + p_nb_bytes).max(0) as usize;
+            p_nb_bytes
+        }
+
+        unsafe extern "C" fn opj_stream_seek_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i32 {
+            let user_data = &mut *(p_user_data as *mut VecWithOffset);
+            user_data.offset = p_nb_bytes.max(0) as usize;
+            1
+        }
+
+        let user_data = Box::new(VecWithOffset { buf: buf as *mut _, offset: 0 });
+
+        let ptr = unsafe {
+            let jp2_stream = ffi::opj_stream_default_create(0);
+            ffi::opj_stream_set_write_function(jp2_stream, Some(opj_stream_write_fn));
+            ffi::opj_stream_set_skip_function(jp2_stream, Some(opj_stream_skip_fn));
+            ffi::opj_stream_set_seek_function(jp2_stream, Some(opj_stream_seek_fn));
+            ffi::opj_stream_set_user_data(
+                jp2_stream,
+                Box::into_raw(user_data) as *mut c_void,
+                Some(opj_stream_free_user_data_fn),
+            );
+            jp2_stream
+        };
+
+        Stream(ptr, PhantomData)
+    }
+}
+
+pub struct Codec(NonNull<ffi::opj_codec_t>);
+
+impl Drop for Codec {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::opj_destroy_codec(self.0.as_ptr());
+        }
+    }
+}
+
+impl Codec {
+    pub fn jp2() -> Self {
+        Self::create(CODEC_FORMAT::OPJ_CODEC_JP2).expect("Known format `JP2` should not fail")
+    }
+
+    pub fn create(format: CODEC_FORMAT) -> err::Result<Self> {
+        match NonNull::new(unsafe { ffi::opj_create_decompress(format) }) {
+            Some(ptr) => Ok(Codec(ptr)),
+            None => Err(err::Error::boxed("Setting up the decoder failed.")),
+        }
+    }
+
+    pub fn create_encoder(format: CODEC_FORMAT) -> err::Result<Self> {
+        match NonNull::new(unsafe { ffi::opj_create_compress(format) }) {
+            Some(ptr) => Ok(Codec(ptr)),
+            None => Err(err::Error::boxed("Setting up the encoder failed.")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Info {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Info {
+    pub fn build(codec: Codec, stream: Stream) -> err::Result<Self> {
+        let mut params = InnerDecodeParams::default();
+
+        params.0.flags |= ffi::OPJ_DPARAMETERS_DUMP_FLAG;
+
+        if unsafe { ffi::opj_setup_decoder(codec.0.as_ptr(), &mut params.0) } != 1 {
+            return Err(err::Error::boxed("Setting up the decoder failed."));
+        }
+
+        let mut img = Image::new();
+
+        if unsafe { ffi::opj_read_header(stream.0, codec.0.as_ptr(), &mut img.0) } != 1 {
+            return Err(err::Error::boxed("Failed to read header."));
+        }
+
+        Ok(Info { width: img.width(), height: img.height() })
+    }
+}
+
+#[derive(Debug)]
+pub struct Image(pub *mut ffi::opj_image_t);
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::opj_image_destroy(self.0);
+        }
+    }
+}
+
+impl Image {
+    fn new() -> Self {
+        Image(ptr::null_mut())
+    }
+
+    pub fn width(&self) -> u32 {
+        unsafe { (&*self.0).x1 - (&*self.0).x0 }
+    }
+
+    pub fn height(&self) -> u32 {
+        unsafe { (&*self.0).y1 - (&*self.0).y0 }
+    }
+
+    pub fn num_components(&self) -> u32 {
+        unsafe { (*self.0).numcomps }
+    }
+
+    pub fn components(&self) -> &[ffi::opj_image_comp_t] {
+        let comps_len = self.num_components();
+        unsafe { std::slice::from_raw_parts((*self.0).comps, comps_len as usize) }
+    }
+
+    pub fn factor(&self) -> u32 {
+        unsafe { (*(*self.0).comps).factor }
+    }
+
+    pub fn color_space(&self) -> COLOR_SPACE {
+        unsafe { (*self.0).color_space }
+    }
+
+    /// Bits per sample of the first component
+    pub fn precision(&self) -> u32 {
+        unsafe { (*(*self.0).comps).prec }
+    }
+
+    /// Whether the samples of the first component are signed
+    pub fn signed(&self) -> bool {
+        unsafe { (*(*self.0).comps).sgnd == 1 }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Component(*mut ffi::opj_image_comp_t);
+
+#[derive(Debug)]
+pub struct ImageBuffer {
+    pub buffer: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub num_bands: usize,
+}
+
+impl ImageBuffer {
+    /// Samples brought to 8 bits from their precision, signed ones offset to start at 0
+    pub fn build(codec: Codec, stream: Stream, params: DecodeParams) -> err::Result<Self> {
+        let samples = SampleBuffer::build(codec, stream, params)?;
+        Ok(ImageBuffer {
+            buffer: samples.to_u8(),
+            width: samples.width,
+            height: samples.height,
+            num_bands: samples.num_bands,
+        })
+    }
+}
+
+/// Decoded samples at their full precision, interleaved by pixel
+#[derive(Debug)]
+pub struct SampleBuffer {
+    pub samples: Vec<i32>,
+    pub width: u32,
+    pub height: u32,
+    pub num_bands: usize,
+    /// Bits per sample, from the first component
+    pub precision: u32,
+    /// Whether the samples are two's complement, from the first component
+    pub signed: bool,
+}
+
+impl SampleBuffer {
+    pub fn build(codec: Codec, stream: Stream, params: DecodeParams) -> err::Result<Self> {
+        let mut inner_params = InnerDecodeParams::default();
+
+        if let Some(reduce_factor) = params.reduce_factor {
+            inner_params.0.cp_reduce = reduce_factor;
+        }
+
+        if let Some(quality_layers) = params.quality_layers {
+            inner_params.0.cp_layer = quality_layers;
+        }
+
+        if unsafe { ffi::opj_setup_decoder(codec.0.as_ptr(), &mut inner_params.0) } != 1 {
+            return Err(err::Error::boxed("Setting up the decoder failed."));
+        }
+
+        if let Some(num_threads) = params.num_threads {
+            if unsafe { ffi::opj_codec_set_threads(codec.0.as_ptr(), num_threads) } != 1 {
+                return Err(err::Error::boxed("Could not set specified threads."));
+            }
+        }
+
+        let mut img = Image::new();
+
+        if unsafe { ffi::opj_read_header(stream.0, codec.0.as_ptr(), &mut img.0) } != 1 {
+            return Err(err::Error::boxed("Failed to read header."));
+        }
+
+        if let Some(DecodingArea { x0, y0, x1, y1 }) = params.decoding_area {
+            if unsafe { ffi::opj_set_decode_area(codec.0.as_ptr(), img.0, x0, y0, x1, y1) } != 1 {
+                return Err(err::Error::boxed("Setting up the decoding area failed."));
+            }
+        }
+
+        if unsafe { ffi::opj_decode(codec.0.as_ptr(), stream.0, img.0) } != 1 {
+            return Err(err::Error::boxed("Failed to read image."));
+        }
+
+        // if unsafe { ffi::opj_end_decompress(codec.0.as_ptr(), stream.0) } != 1 {
+        //     return Err(err::Error::boxed("Ending decoding failed."));
+        // }
+
+        drop(codec);
+        drop(stream);
+
+        let components = img.components();
+        if !matches!(components.len(), 1 | 3 | 4) {
+            return Err(err::Error::boxed("Operation not supported for that number of components"));
+        }
+        // Sizes of the decoded components, reduced and cropped. Subsampled components
+        // (dx, dy > 1) are smaller than the first one and can't be interleaved with it
+        let (width, height) = (components[0].w, components[0].h);
+        if components.iter().any(|comp| comp.w != width || comp.h != height || comp.data.is_null()) {
+            return Err(err::Error::boxed("Operation not supported for subsampled components"));
+        }
+        let len = (width * height) as usize;
+
+        let planes = components
+            .iter()
+            .map(|comp| unsafe { std::slice::from_raw_parts(comp.data, len) })
+            .collect::<Vec<_>>();
+        let mut samples = Vec::with_capacity(len * planes.len());
+        for i in 0..len {
+            samples.extend(planes.iter().map(|plane| plane[i]));
+        }
+
+        Ok(SampleBuffer {
+            samples,
+            width,
+            height,
+            num_bands: components.len(),
+            precision: img.precision(),
+            signed: img.signed(),
+        })
+    }
+
+    /// Samples brought to 8 bits from their precision, signed ones offset to start at 0
+    pub fn to_u8(&self) -> Vec<u8> {
+        let precision = self.precision.clamp(1, 32) as i64;
+        let offset = if self.signed { 1 << (precision - 1) } else { 0 };
+        self.samples
+            .iter()
+            .map(|x| {
+                let x = *x as i64 + offset;
+                let x = if precision > 8 { x >> (precision - 8) } else { x << (8 - precision) };
+                x.clamp(0, 255) as u8
+            })
+            .collect()
+    }
+}
+
+impl SampleBuffer {
+    /// J2K codestream of the samples, 1 or 3 components of up to 16 bits
+    pub fn encode(&self, params: EncodeParams) -> err::Result<Vec<u8>> {
+        if self.num_bands != 1 && self.num_bands != 3 {
+            return Err(err::Error::boxed("Encoding is supported for 1 or 3 components only"));
+        }
+        if self.precision == 0 || self.precision > 16 {
+            return Err(err::Error::boxed("Encoding is supported up to 16 bits per sample"));
+        }
+        let len = (self.width * self.height) as usize;
+        if len == 0 || self.samples.len() != len * self.num_bands {
+            return Err(err::Error::boxed("Sample count doesn't match the image size"));
+        }
+
+        let mut inner_params = unsafe { std::mem::zeroed::<ffi::opj_cparameters_t>() };
+        unsafe {
+            ffi::opj_set_default_encoder_parameters(&mut inner_params as *mut _);
+        }
+
+        inner_params.tcp_numlayers = 1;
+        match params.compression {
+            Compression::Lossless => {
+                inner_params.irreversible = 0;
+                inner_params.tcp_rates[0] = 0.0;
+                inner_params.cp_disto_alloc = 1;
+            }
+            Compression::Rate(ratio) => {
+                inner_params.irreversible = 1;
+                inner_params.tcp_rates[0] = ratio;
+                inner_params.cp_disto_alloc = 1;
+            }
+            Compression::Quality(psnr) => {
+                inner_params.irreversible = 1;
+                inner_params.tcp_distoratio[0] = psnr;
+                inner_params.cp_fixed_quality = 1;
+            }
+        }
+        inner_params.tcp_mct = (params.color_transform && self.num_bands == 3) as _;
+
+        // Each resolution halves the image, down to a single pixel at most
+        let max_resolutions = 32 - self.width.min(self.height).leading_zeros();
+        inner_params.numresolution =
+            params.num_resolutions.unwrap_or(6).clamp(1, max_resolutions) as _;
+
+        let mut comp_params = (0..self.num_bands)
+            .map(|_| {
+                let mut comp = unsafe { std::mem::zeroed::<ffi::opj_image_cmptparm_t>() };
+                comp.dx = 1;
+                comp.dy = 1;
+                comp.w = self.width;
+                comp.h = self.height;
+                comp.prec = self.precision;
+                comp.sgnd = self.signed as u32;
+                comp
+            })
+            .collect::<Vec<_>>();
+
+        let color_space = if self.num_bands == 3 {
+            COLOR_SPACE::OPJ_CLRSPC_SRGB
+        } else {
+            COLOR_SPACE::OPJ_CLRSPC_GRAY
+        };
+
+        let img = Image(unsafe {
+            ffi::opj_image_create(self.num_bands as u32, comp_params.as_mut_ptr(), color_space)
+        });
+        if img.0.is_null() {
+            return Err(err::Error::boxed("Creating the image failed."));
+        }
+
+        unsafe {
+            (*img.0).x0 = 0;
+            (*img.0).y0 = 0;
+            (*img.0).x1 = self.width;
+            (*img.0).y1 = self.height;
+
+            for (band, comp) in img.components().iter().enumerate() {
+                let plane = std::slice::from_raw_parts_mut(comp.data, len);
+                for (i, sample) in plane.iter_mut().enumerate() {
+                    *sample = self.samples[i * self.num_bands + band];
+                }
+            }
+        }
+
+        let codec = Codec::create_encoder(CODEC_FORMAT::OPJ_CODEC_J2K)?;
+
+        if unsafe { ffi::opj_setup_encoder(codec.0.as_ptr(), &mut inner_params, img.0) } != 1 {
+            return Err(err::Error::boxed("Setting up the encoder failed."));
+        }
+
+        let mut output = Vec::new();
+        let stream = Stream::to_vec(&mut output);
+
+        if unsafe { ffi::opj_start_compress(codec.0.as_ptr(), img.0, stream.0) } != 1 {
+            return Err(err::Error::boxed("Starting compression failed."));
+        }
+
+        if unsafe { ffi::opj_encode(codec.0.as_ptr(), stream.0) } != 1 {
+            return Err(err::Error::boxed("Failed to encode image."));
+        }
+
+        if unsafe { ffi::opj_end_compress(codec.0.as_ptr(), stream.0) } != 1 {
+            return Err(err::Error::boxed("Ending compression failed."));
+        }
+
+        drop(stream);
+        drop(codec);
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Samples over the whole range of `precision`, the same from one run to the other
+    fn samples(len: usize, precision: u32, signed: bool) -> Vec<i32> {
+        let range = 1i64 << precision;
+        let offset = if signed { range / 2 } else { 0 };
+        let mut state = 0x2545_F491u64;
+        (0..len)
+            .map(|i| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                // Extremes first, then anything in range
+                let value = match i {
+                    0 => 0,
+                    1 => range - 1,
+                    _ => (state >> 33) as i64 % range,
+                };
+                (value - offset) as i32
+            })
+            .collect()
+    }
+
+    fn encode_decode(original: &SampleBuffer, params: EncodeParams) -> (Vec<u8>, SampleBuffer) {
+        let codestream = original.encode(params).unwrap();
+        let stream = Stream::from_bytes(&codestream).unwrap();
+        let codec = Codec::create(CODEC_FORMAT::OPJ_CODEC_J2K).unwrap();
+        let decoded = SampleBuffer::build(codec, stream, DecodeParams::default()).unwrap();
+
+        assert_eq!((decoded.width, decoded.height), (original.width, original.height));
+        assert_eq!(decoded.num_bands, original.num_bands);
+        assert_eq!((decoded.precision, decoded.signed), (original.precision, original.signed));
+        (codestream, decoded)
+    }
+
+    fn round_trip(num_bands: usize, precision: u32, signed: bool, params: EncodeParams) {
+        let (width, height) = (37, 23);
+        let original = SampleBuffer {
+            samples: samples(width * height * num_bands, precision, signed),
+            width: width as u32,
+            height: height as u32,
+            num_bands,
+            precision,
+            signed,
+        };
+        let (_, decoded) = encode_decode(&original, params);
+        assert!(decoded.samples == original.samples, "{} bits, {} components", precision, num_bands);
+    }
+
+    /// 12-bit signed gradient with ripples, smooth enough for the 9/7 wavelet to keep close to it
+    fn smooth_image() -> SampleBuffer {
+        let (width, height) = (128, 128);
+        let samples = (0..width * height)
+            .map(|i| {
+                let (x, y) = ((i % width) as f64, (i / width) as f64);
+                let value = (x + y) * 12. + (x / 6.).sin() * 200. + (y / 9.).cos() * 200.;
+                (value - 1536.).round().clamp(-2048., 2047.) as i32
+            })
+            .collect();
+        SampleBuffer { samples, width: width as u32, height: height as u32, num_bands: 1, precision: 12, signed: true }
+    }
+
+    /// Peak signal to noise ratio of `decoded`, in dB
+    fn psnr(original: &SampleBuffer, decoded: &SampleBuffer) -> f64 {
+        let mse = original
+            .samples
+            .iter()
+            .zip(&decoded.samples)
+            .map(|(a, b)| ((a - b) as f64).powi(2))
+            .sum::<f64>()
+            / original.samples.len() as f64;
+        let peak = ((1u32 << original.precision) - 1) as f64;
+        10. * (peak * peak / mse.max(f64::MIN_POSITIVE)).log10()
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        for num_bands in [1, 3] {
+            round_trip(num_bands, 8, false, EncodeParams::default());
+            round_trip(num_bands, 12, false, EncodeParams::default());
+            round_trip(num_bands, 16, true, EncodeParams::default());
+        }
+    }
+
+    #[test]
+    fn lossless_round_trip_with_color_transform() {
+        let params = EncodeParams::default().with_color_transform(true);
+        round_trip(3, 8, false, params.clone());
+        round_trip(3, 16, true, params);
+    }
+
+    #[test]
+    fn lossy_at_a_rate() {
+        let original = smooth_image();
+        let raw_len = original.samples.len() * original.precision as usize / 8;
+        let (codestream, decoded) = encode_decode(&original, EncodeParams::default().with_compression(Compression::Rate(10.)));
+        // 10:1, with some room for the headers
+        assert!(codestream.len() * 5 < raw_len, "{} bytes of {}", codestream.len(), raw_len);
+        assert!(decoded.samples != original.samples);
+        assert!(psnr(&original, &decoded) > 30., "{} dB", psnr(&original, &decoded));
+    }
+
+    #[test]
+    fn lossy_at_a_quality() {
+        let original = smooth_image();
+        let raw_len = original.samples.len() * original.precision as usize / 8;
+        let (codestream, decoded) = encode_decode(&original, EncodeParams::default().with_compression(Compression::Quality(40.)));
+        assert!(codestream.len() < raw_len, "{} bytes of {}", codestream.len(), raw_len);
+        assert!(psnr(&original, &decoded) > 35., "{} dB", psnr(&original, &decoded));
+    }
+
+    #[test]
+    fn reject_unsupported_samples() {
+        let buffer = SampleBuffer { samples: vec![0; 8], width: 2, height: 2, num_bands: 2, precision: 8, signed: false };
+        assert!(buffer.encode(EncodeParams::default()).is_err());
+        let buffer = SampleBuffer { num_bands: 1, ..buffer };
+        assert!(buffer.encode(EncodeParams::default()).is_err());
+        let buffer = SampleBuffer { samples: vec![0; 4], precision: 17, ..buffer };
+        assert!(buffer.encode(EncodeParams::default()).is_err());
+    }
+}